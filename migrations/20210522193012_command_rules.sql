@@ -0,0 +1,20 @@
+-- Add migration script here
+CREATE TABLE public.command_rules
+(
+    guild_id bigint NOT NULL,
+    name text COLLATE pg_catalog."default" NOT NULL,
+    is_group boolean NOT NULL,
+    scope_type text COLLATE pg_catalog."default" NOT NULL,
+    scope_id bigint NOT NULL,
+    enabled boolean NOT NULL,
+    CONSTRAINT command_rules_pkey PRIMARY KEY (guild_id, name, is_group, scope_type, scope_id),
+    CONSTRAINT "FK_command_rules_guild_info_guild_id" FOREIGN KEY (guild_id)
+        REFERENCES public.guild_info (guild_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.command_rules
+    OWNER to postgres;
@@ -0,0 +1,10 @@
+-- Add migration script here
+ALTER TABLE public.guild_info
+    ADD COLUMN prefixes text[] COLLATE pg_catalog."default";
+
+UPDATE public.guild_info
+    SET prefixes = ARRAY[prefix]
+    WHERE prefix IS NOT NULL;
+
+ALTER TABLE public.guild_info
+    DROP COLUMN prefix;
@@ -0,0 +1,28 @@
+-- Add migration script here
+CREATE TABLE public.command_usage
+(
+    id bigserial NOT NULL,
+    guild_id bigint,
+    channel_id bigint NOT NULL,
+    user_id bigint NOT NULL,
+    command text COLLATE pg_catalog."default" NOT NULL,
+    duration_ms integer NOT NULL,
+    success boolean NOT NULL,
+    used_at timestamp with time zone NOT NULL DEFAULT now(),
+    CONSTRAINT command_usage_pkey PRIMARY KEY (id)
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.command_usage
+    OWNER to postgres;
+
+CREATE INDEX command_usage_used_at_idx
+    ON public.command_usage USING btree
+    (used_at ASC NULLS LAST)
+    TABLESPACE pg_default;
+
+CREATE INDEX command_usage_guild_id_used_at_idx
+    ON public.command_usage USING btree
+    (guild_id ASC NULLS LAST, used_at ASC NULLS LAST)
+    TABLESPACE pg_default;
@@ -0,0 +1,23 @@
+-- Add migration script here
+CREATE TABLE public.command_errors
+(
+    error_id uuid NOT NULL,
+    guild_id bigint,
+    channel_id bigint NOT NULL,
+    user_id bigint NOT NULL,
+    command text COLLATE pg_catalog."default" NOT NULL,
+    arguments text COLLATE pg_catalog."default" NOT NULL,
+    error text COLLATE pg_catalog."default" NOT NULL,
+    created_at timestamp with time zone NOT NULL DEFAULT now(),
+    CONSTRAINT command_errors_pkey PRIMARY KEY (error_id)
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.command_errors
+    OWNER to postgres;
+
+CREATE INDEX command_errors_created_at_idx
+    ON public.command_errors USING btree
+    (created_at DESC NULLS LAST)
+    TABLESPACE pg_default;
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE public.guild_info
+    ADD COLUMN suggestions boolean NOT NULL DEFAULT true;
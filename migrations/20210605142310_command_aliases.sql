@@ -0,0 +1,17 @@
+-- Add migration script here
+CREATE TABLE public.command_aliases
+(
+    guild_id bigint NOT NULL,
+    alias text COLLATE pg_catalog."default" NOT NULL,
+    command text COLLATE pg_catalog."default" NOT NULL,
+    CONSTRAINT command_aliases_pkey PRIMARY KEY (guild_id, alias),
+    CONSTRAINT "FK_command_aliases_guild_info_guild_id" FOREIGN KEY (guild_id)
+        REFERENCES public.guild_info (guild_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.command_aliases
+    OWNER to postgres;
@@ -0,0 +1,17 @@
+-- Add migration script here
+CREATE TABLE public.permission_roles
+(
+    guild_id bigint NOT NULL,
+    level text COLLATE pg_catalog."default" NOT NULL,
+    role_id bigint NOT NULL,
+    CONSTRAINT permission_roles_pkey PRIMARY KEY (guild_id, level, role_id),
+    CONSTRAINT "FK_permission_roles_guild_info_guild_id" FOREIGN KEY (guild_id)
+        REFERENCES public.guild_info (guild_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.permission_roles
+    OWNER to postgres;
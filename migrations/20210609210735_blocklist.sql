@@ -0,0 +1,14 @@
+-- Add migration script here
+CREATE TABLE public.blocklist
+(
+    target_type text COLLATE pg_catalog."default" NOT NULL,
+    target_id bigint NOT NULL,
+    reason text COLLATE pg_catalog."default",
+    created_at timestamp with time zone NOT NULL DEFAULT now(),
+    CONSTRAINT blocklist_pkey PRIMARY KEY (target_type, target_id)
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.blocklist
+    OWNER to postgres;
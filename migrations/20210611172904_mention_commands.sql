@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE public.guild_info
+    ADD COLUMN mention_commands text[] COLLATE pg_catalog."default" NOT NULL DEFAULT '{}';
@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE public.commands
+    ADD COLUMN created_by bigint,
+    ADD COLUMN created_at timestamp with time zone NOT NULL DEFAULT now(),
+    ADD COLUMN updated_at timestamp with time zone NOT NULL DEFAULT now();
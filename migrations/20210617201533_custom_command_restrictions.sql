@@ -0,0 +1,5 @@
+-- Add migration script here
+ALTER TABLE public.commands
+    ADD COLUMN allowed_roles bigint[] NOT NULL DEFAULT '{}',
+    ADD COLUMN allowed_channels bigint[] NOT NULL DEFAULT '{}',
+    ADD COLUMN cooldown integer;
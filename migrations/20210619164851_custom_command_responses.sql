@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE public.commands
+    ADD COLUMN responses jsonb;
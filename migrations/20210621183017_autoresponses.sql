@@ -0,0 +1,27 @@
+-- Add migration script here
+CREATE TABLE public.autoresponses
+(
+    response_id integer NOT NULL GENERATED ALWAYS AS IDENTITY,
+    guild_id bigint NOT NULL,
+    trigger_type text COLLATE pg_catalog."default" NOT NULL,
+    trigger text COLLATE pg_catalog."default" NOT NULL,
+    response text COLLATE pg_catalog."default",
+    reaction text COLLATE pg_catalog."default",
+    cooldown integer,
+    channel_ids bigint[] NOT NULL DEFAULT '{}',
+    CONSTRAINT autoresponses_pkey PRIMARY KEY (response_id),
+    CONSTRAINT "FK_autoresponses_guild_info_guild_id" FOREIGN KEY (guild_id)
+        REFERENCES public.guild_info (guild_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.autoresponses
+    OWNER to postgres;
+
+CREATE INDEX autoresponses_guild_id_idx
+    ON public.autoresponses USING btree
+    (guild_id ASC NULLS LAST)
+    TABLESPACE pg_default;
@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE public.commands
+    ADD COLUMN category text COLLATE pg_catalog."default";
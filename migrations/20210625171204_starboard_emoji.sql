@@ -0,0 +1,3 @@
+-- Add migration script here
+ALTER TABLE public.guild_info
+    ADD COLUMN starboard_emoji text COLLATE pg_catalog."default";
@@ -0,0 +1,57 @@
+-- Add migration script here
+CREATE TABLE public.starboards
+(
+    guild_id bigint NOT NULL,
+    name text COLLATE pg_catalog."default" NOT NULL,
+    emoji text COLLATE pg_catalog."default" NOT NULL,
+    threshold integer NOT NULL,
+    channel_id bigint NOT NULL,
+    CONSTRAINT starboards_pkey PRIMARY KEY (guild_id, name),
+    CONSTRAINT "FK_starboards_guild_info_guild_id" FOREIGN KEY (guild_id)
+        REFERENCES public.guild_info (guild_id) MATCH SIMPLE
+        ON UPDATE NO ACTION
+        ON DELETE CASCADE
+)
+
+TABLESPACE pg_default;
+
+ALTER TABLE public.starboards
+    OWNER to postgres;
+
+-- Existing starboards become a board named "starboard"
+INSERT INTO public.starboards
+    SELECT guild_info.guild_id, 'starboard', COALESCE(guild_info.starboard_emoji, '⭐'),
+        guild_info.starboard_threshold, text_channels.quote_id
+    FROM public.guild_info
+    INNER JOIN public.text_channels ON guild_info.guild_id = text_channels.guild_id
+    WHERE guild_info.starboard_threshold IS NOT NULL AND text_channels.quote_id IS NOT NULL;
+
+ALTER TABLE public.guild_info
+    DROP COLUMN starboard_threshold;
+
+ALTER TABLE public.guild_info
+    DROP COLUMN starboard_emoji;
+
+ALTER TABLE public.starboard
+    RENAME TO starboard_messages;
+
+DELETE FROM public.starboard_messages
+    WHERE guild_id NOT IN (SELECT guild_id FROM public.starboards);
+
+ALTER TABLE public.starboard_messages
+    ADD COLUMN board_name text COLLATE pg_catalog."default" NOT NULL DEFAULT 'starboard';
+
+ALTER TABLE public.starboard_messages
+    ALTER COLUMN board_name DROP DEFAULT;
+
+ALTER TABLE public.starboard_messages
+    DROP CONSTRAINT starbot_pkey;
+
+ALTER TABLE public.starboard_messages
+    ADD CONSTRAINT starboard_messages_pkey PRIMARY KEY (guild_id, board_name, reaction_message_id);
+
+ALTER TABLE public.starboard_messages
+    ADD CONSTRAINT "FK_starboard_messages_starboards_name" FOREIGN KEY (guild_id, board_name)
+        REFERENCES public.starboards (guild_id, name) MATCH SIMPLE
+        ON UPDATE CASCADE
+        ON DELETE CASCADE;
@@ -0,0 +1,9 @@
+-- Add migration script here
+ALTER TABLE public.guild_info
+    ADD COLUMN starboard_ignore_self boolean NOT NULL DEFAULT false;
+
+ALTER TABLE public.guild_info
+    ADD COLUMN starboard_ignore_bots boolean NOT NULL DEFAULT false;
+
+ALTER TABLE public.guild_info
+    ADD COLUMN starboard_ignore_bot_stars boolean NOT NULL DEFAULT false;
@@ -0,0 +1,214 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+use std::collections::HashMap;
+
+use crate::{
+    helpers::{locale_helper, permissions_helper::MODERATOR_CHECK},
+    AliasMap, CommandGroupMap, CommandNameMap, ConnectionPool, CustomCommandMap, JesterError,
+};
+
+/// Server shorthands for built-in commands
+#[command]
+#[sub_commands(alias_add, alias_remove, alias_list)]
+#[only_in(guilds)]
+async fn alias(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"add, remove, list")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Adds a shorthand for a built-in command. The command can include subcommands
+#[command("add")]
+#[usage = "<alias> <command>"]
+#[example = "np queue"]
+#[example = "sbl starboard list"]
+#[checks(Moderator)]
+#[min_args(2)]
+#[only_in(guilds)]
+async fn alias_add(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, alias_map, group_map, command_names, custom_command_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let alias_map = data.get::<AliasMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+        let command_names = data.get::<CommandNameMap>().cloned().unwrap();
+        let custom_command_map = data.get::<CustomCommandMap>().cloned().unwrap();
+
+        (
+            pool,
+            alias_map,
+            group_map,
+            command_names,
+            custom_command_map,
+        )
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let alias = args.single::<String>()?;
+    let target = args.rest().trim().to_owned();
+
+    // Aliases can't shadow anything that already runs when invoked
+    if command_names.contains(&alias) {
+        msg.channel_id
+            .say(ctx, locale.get("alias.hardcoded"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let custom_exists = custom_command_map
+        .get(&guild_id)
+        .map_or(false, |commands| commands.contains_key(&alias));
+
+    if custom_exists {
+        msg.channel_id
+            .say(ctx, locale.get("alias.custom_command"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let target_command = target.split_whitespace().next().unwrap_or_default();
+
+    if !group_map.contains_key(target_command) {
+        msg.channel_id
+            .say(
+                ctx,
+                JesterError::MissingError("valid command name").localize(&locale),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "INSERT INTO command_aliases(guild_id, alias, command)
+            VALUES($1, $2, $3)
+            ON CONFLICT (guild_id, alias)
+            DO UPDATE
+            SET command = EXCLUDED.command",
+        guild_id.0 as i64,
+        alias,
+        target
+    )
+    .execute(&pool)
+    .await?;
+
+    alias_map
+        .entry(guild_id)
+        .or_insert_with(HashMap::new)
+        .insert(alias.clone(), target.clone());
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("alias.set", &[("alias", &alias), ("command", &target)]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Removes one of the server's aliases
+#[command("remove")]
+#[usage = "<alias>"]
+#[checks(Moderator)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn alias_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, alias_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let alias_map = data.get::<AliasMap>().cloned().unwrap();
+
+        (pool, alias_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let alias = args.single::<String>()?;
+
+    let exists = alias_map
+        .get(&guild_id)
+        .map_or(false, |aliases| aliases.contains_key(&alias));
+
+    if !exists {
+        msg.channel_id
+            .say(ctx, locale.format("alias.unknown", &[("alias", &alias)]))
+            .await?;
+
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "DELETE FROM command_aliases WHERE guild_id = $1 AND alias = $2",
+        guild_id.0 as i64,
+        alias
+    )
+    .execute(&pool)
+    .await?;
+
+    if let Some(mut aliases) = alias_map.get_mut(&guild_id) {
+        aliases.remove(&alias);
+    }
+
+    msg.channel_id
+        .say(ctx, locale.format("alias.removed", &[("alias", &alias)]))
+        .await?;
+
+    Ok(())
+}
+
+/// Lists all of the server's aliases
+#[command("list")]
+#[only_in(guilds)]
+async fn alias_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let alias_map = ctx.data.read().await.get::<AliasMap>().cloned().unwrap();
+
+    let mut aliases = match alias_map.get(&msg.guild_id.unwrap()) {
+        Some(aliases) => aliases
+            .iter()
+            .map(|(alias, command)| (alias.to_owned(), command.to_owned()))
+            .collect::<Vec<(String, String)>>(),
+        None => Vec::new(),
+    };
+
+    if aliases.is_empty() {
+        msg.channel_id.say(ctx, locale.get("alias.none")).await?;
+
+        return Ok(());
+    }
+
+    aliases.sort();
+
+    let alias_string = aliases
+        .iter()
+        .map(|(alias, command)| format!("`{}` → `{}`", alias, command))
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("alias.list_title"));
+                e.description(alias_string)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,385 @@
+use std::convert::TryFrom;
+
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+    utils::parse_channel,
+};
+
+use crate::{
+    helpers::{cooldown_helper, locale_helper, permissions_helper::MODERATOR_CHECK},
+    structures::{AutoResponder, TriggerType},
+    AutoResponderMap, ConnectionPool, JesterError,
+};
+
+// Every message in a guild is checked against all of its triggers
+const MAX_AUTORESPONDERS: usize = 50;
+
+/// Replies or reacts to messages that match a trigger, without needing a prefix
+#[command]
+#[checks(Moderator)]
+#[sub_commands(autoresponse_add, autoresponse_remove, autoresponse_list)]
+#[only_in(guilds)]
+async fn autoresponse(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"add, remove, list")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Adds an auto-responder. Triggers are a whole message, a phrase in a message, or a regex
+/// Options before the response add a reaction, a cooldown per channel, or limit it to channels
+#[command("add")]
+#[usage = "<exact/contains/regex> <trigger> (reaction:<emoji>) (cooldown:<seconds>) (channel:<channel>) (response)"]
+#[example = "contains 69 nice"]
+#[example = "contains bruh reaction:💀"]
+#[example = "regex \"^good (morning|night)\" channel:#general cooldown:60 Hello!"]
+#[checks(Moderator)]
+#[min_args(2)]
+#[only_in(guilds)]
+async fn autoresponse_add(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let trigger_type = match TriggerType::from_name(&args.single::<String>()?.to_lowercase()) {
+        Some(trigger_type) => trigger_type,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("trigger type of exact, contains or regex")
+                        .localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    // Triggers with spaces have to be quoted
+    let trigger = args.single_quoted::<String>()?;
+
+    let matcher = match trigger_type.compile(&trigger) {
+        Ok(matcher) => matcher,
+        Err(e) => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("autoresponse.invalid_regex", &[("error", &e)]),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let mut reaction = None;
+    let mut cooldown = None;
+    let mut channels = Vec::new();
+
+    while let Some(option) = args.current() {
+        let option = option.to_owned();
+
+        let valid = if let Some(emoji) = option.strip_prefix("reaction:") {
+            reaction = Some(emoji.to_owned());
+            ReactionType::try_from(emoji).is_ok()
+        } else if let Some(seconds) = option.strip_prefix("cooldown:") {
+            cooldown = seconds.parse::<u64>().ok().filter(|seconds| {
+                *seconds > 0 && *seconds <= cooldown_helper::MAX_COOLDOWN_SECONDS
+            });
+            cooldown.is_some()
+        } else if let Some(channel) = option.strip_prefix("channel:") {
+            let channel_id = parse_channel(channel).or_else(|| channel.parse::<u64>().ok());
+            channels.extend(channel_id.map(ChannelId));
+            channel_id.is_some()
+        } else {
+            break;
+        };
+
+        if !valid {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("autoresponse.invalid_option", &[("option", &option)]),
+                )
+                .await?;
+
+            return Ok(());
+        }
+
+        args.advance();
+    }
+
+    let response = match args.rest().trim() {
+        "" => None,
+        response => Some(response.to_owned()),
+    };
+
+    if response.is_none() && reaction.is_none() {
+        msg.channel_id
+            .say(
+                ctx,
+                JesterError::MissingError("response or reaction").localize(&locale),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    // Discord rejects emojis the bot can't use, so reacting to the command checks the emoji
+    if let Some(reaction) = &reaction {
+        if msg
+            .react(ctx, ReactionType::try_from(reaction.as_str())?)
+            .await
+            .is_err()
+        {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("autoresponse.invalid_option", &[("option", reaction)]),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    }
+
+    let (pool, autoresponder_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let autoresponder_map = data.get::<AutoResponderMap>().cloned().unwrap();
+
+        (pool, autoresponder_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    let count = autoresponder_map
+        .get(&guild_id)
+        .map_or(0, |autoresponders| autoresponders.len());
+
+    if count >= MAX_AUTORESPONDERS {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("autoresponse.limit", &[("limit", &MAX_AUTORESPONDERS)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let channel_ids = channels
+        .iter()
+        .map(|channel_id| channel_id.0 as i64)
+        .collect::<Vec<i64>>();
+
+    let id = sqlx::query!(
+        "INSERT INTO autoresponses(guild_id, trigger_type, trigger, response, reaction, cooldown, channel_ids)
+            VALUES($1, $2, $3, $4, $5, $6, $7)
+            RETURNING response_id",
+        guild_id.0 as i64,
+        trigger_type.as_str(),
+        trigger,
+        response,
+        reaction,
+        cooldown.map(|seconds| seconds as i32),
+        &channel_ids
+    )
+    .fetch_one(&pool)
+    .await?
+    .response_id;
+
+    autoresponder_map
+        .entry(guild_id)
+        .or_insert_with(Vec::new)
+        .push(AutoResponder {
+            id,
+            trigger_type,
+            trigger,
+            matcher,
+            response,
+            reaction,
+            cooldown,
+            channels,
+        });
+
+    msg.channel_id
+        .say(ctx, locale.format("autoresponse.added", &[("id", &id)]))
+        .await?;
+
+    Ok(())
+}
+
+/// Removes an auto-responder by the ID shown in `autoresponse list`
+#[command("remove")]
+#[usage = "<ID>"]
+#[example = "12"]
+#[checks(Moderator)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn autoresponse_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let id = match args.single::<i32>() {
+        Ok(id) => id,
+        Err(_) => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("auto-responder ID").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let (pool, autoresponder_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let autoresponder_map = data.get::<AutoResponderMap>().cloned().unwrap();
+
+        (pool, autoresponder_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    let result = sqlx::query!(
+        "DELETE FROM autoresponses WHERE guild_id = $1 AND response_id = $2",
+        guild_id.0 as i64,
+        id
+    )
+    .execute(&pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        msg.channel_id
+            .say(ctx, locale.format("autoresponse.unknown", &[("id", &id)]))
+            .await?;
+
+        return Ok(());
+    }
+
+    let is_empty = match autoresponder_map.get_mut(&guild_id) {
+        Some(mut autoresponders) => {
+            autoresponders.retain(|autoresponder| autoresponder.id != id);
+            autoresponders.is_empty()
+        }
+        None => false,
+    };
+
+    if is_empty {
+        autoresponder_map.remove(&guild_id);
+    }
+
+    msg.channel_id
+        .say(ctx, locale.format("autoresponse.removed", &[("id", &id)]))
+        .await?;
+
+    Ok(())
+}
+
+/// Lists the server's auto-responders
+#[command("list")]
+#[checks(Moderator)]
+#[only_in(guilds)]
+async fn autoresponse_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let autoresponder_map = ctx
+        .data
+        .read()
+        .await
+        .get::<AutoResponderMap>()
+        .cloned()
+        .unwrap();
+
+    let autoresponders = match autoresponder_map.get(&msg.guild_id.unwrap()) {
+        Some(autoresponders) => autoresponders.value().to_owned(),
+        None => Vec::new(),
+    };
+
+    if autoresponders.is_empty() {
+        msg.channel_id
+            .say(ctx, locale.get("autoresponse.none"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let mut description = String::new();
+
+    for autoresponder in &autoresponders {
+        let mut entry = format!(
+            "**{}.** {} `{}`",
+            autoresponder.id,
+            autoresponder.trigger_type.as_str(),
+            autoresponder.trigger
+        );
+
+        if let Some(response) = &autoresponder.response {
+            let response = match response.char_indices().nth(100) {
+                Some((index, _)) => format!("{}...", &response[..index]),
+                None => response.to_owned(),
+            };
+
+            entry.push_str(&format!(
+                "\n{}",
+                locale.format("autoresponse.list_response", &[("response", &response)])
+            ));
+        }
+
+        if let Some(reaction) = &autoresponder.reaction {
+            entry.push_str(&format!(
+                "\n{}",
+                locale.format("autoresponse.list_reaction", &[("reaction", reaction)])
+            ));
+        }
+
+        if let Some(seconds) = autoresponder.cooldown {
+            entry.push_str(&format!(
+                "\n{}",
+                locale.format("autoresponse.list_cooldown", &[("seconds", &seconds)])
+            ));
+        }
+
+        if !autoresponder.channels.is_empty() {
+            let channels = autoresponder
+                .channels
+                .iter()
+                .map(|channel_id| channel_id.mention().to_string())
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            entry.push_str(&format!(
+                "\n{}",
+                locale.format("autoresponse.list_channels", &[("channels", &channels)])
+            ));
+        }
+
+        // Embed descriptions can only hold 4096 characters
+        if description.len() + entry.len() > 4000 {
+            break;
+        }
+
+        description.push_str(&entry);
+        description.push_str("\n\n");
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("autoresponse.list_title"));
+                e.description(description)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,262 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+    utils::parse_username,
+};
+
+use crate::{
+    helpers::locale_helper,
+    structures::{BlockTarget, Locale},
+    Blocklist, ConnectionPool,
+};
+
+/// Stops users and servers from using the bot
+#[command]
+#[owners_only(true)]
+#[sub_commands(blocklist_add, blocklist_remove, blocklist_list)]
+async fn blocklist(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"add, remove, list")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Blocks a user or server. The bot leaves blocked servers right away
+#[command("add")]
+#[usage = "<user/guild> <ID or mention> (reason)"]
+#[example = "user 123456789012345678 gifsearch spam"]
+#[owners_only(true)]
+#[min_args(2)]
+async fn blocklist_add(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let target = match parse_target(&mut args) {
+        Some(target) => target,
+        None => {
+            msg.channel_id
+                .say(ctx, locale.get("blocklist.invalid_target"))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let reason = match args.rest().trim() {
+        "" => None,
+        reason => Some(reason.to_owned()),
+    };
+
+    let (pool, blocklist) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let blocklist = data.get::<Blocklist>().cloned().unwrap();
+
+        (pool, blocklist)
+    };
+
+    let (target_type, target_id) = target.to_db();
+
+    sqlx::query!(
+        "INSERT INTO blocklist(target_type, target_id, reason)
+            VALUES($1, $2, $3)
+            ON CONFLICT (target_type, target_id)
+            DO UPDATE
+            SET reason = EXCLUDED.reason",
+        target_type,
+        target_id,
+        reason
+    )
+    .execute(&pool)
+    .await?;
+
+    blocklist.insert(target);
+
+    if let BlockTarget::Guild(guild_id) = target {
+        if guild_id.to_guild_cached(ctx).await.is_some() {
+            guild_id.leave(ctx).await?;
+        }
+    }
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "blocklist.blocked",
+                &[
+                    ("target", &get_target_name(target_type, &locale)),
+                    ("id", &(target_id as u64)),
+                ],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Unblocks a user or server
+#[command("remove")]
+#[usage = "<user/guild> <ID or mention>"]
+#[owners_only(true)]
+#[min_args(2)]
+async fn blocklist_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let target = match parse_target(&mut args) {
+        Some(target) => target,
+        None => {
+            msg.channel_id
+                .say(ctx, locale.get("blocklist.invalid_target"))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let (pool, blocklist) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let blocklist = data.get::<Blocklist>().cloned().unwrap();
+
+        (pool, blocklist)
+    };
+
+    let (target_type, target_id) = target.to_db();
+
+    if blocklist.remove(&target).is_none() {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format(
+                    "blocklist.not_blocked",
+                    &[
+                        ("target", &get_target_name(target_type, &locale)),
+                        ("id", &(target_id as u64)),
+                    ],
+                ),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "DELETE FROM blocklist WHERE target_type = $1 AND target_id = $2",
+        target_type,
+        target_id
+    )
+    .execute(&pool)
+    .await?;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "blocklist.unblocked",
+                &[
+                    ("target", &get_target_name(target_type, &locale)),
+                    ("id", &(target_id as u64)),
+                ],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lists the most recently blocked users and servers
+#[command("list")]
+#[owners_only(true)]
+async fn blocklist_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, blocklist) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let blocklist = data.get::<Blocklist>().cloned().unwrap();
+
+        (pool, blocklist)
+    };
+
+    let blocked = sqlx::query!(
+        "SELECT target_type, target_id, reason, to_char(created_at, 'YYYY-MM-DD') AS \"created_at!\"
+            FROM blocklist ORDER BY created_at DESC LIMIT 20"
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    if blocked.is_empty() {
+        msg.channel_id
+            .say(ctx, locale.get("blocklist.none"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let blocked_string = blocked
+        .iter()
+        .map(|x| {
+            let reason = match &x.reason {
+                Some(reason) => reason.to_owned(),
+                None => locale.get("blocklist.no_reason"),
+            };
+
+            locale.format(
+                "blocklist.list_entry",
+                &[
+                    ("target", &get_target_name(&x.target_type, &locale)),
+                    ("id", &(x.target_id as u64)),
+                    ("date", &x.created_at),
+                    ("reason", &reason),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.color(0xff69b4);
+                e.title(locale.get("blocklist.list_title"));
+                e.description(blocked_string);
+                e.footer(|f| {
+                    f.text(locale.format("blocklist.list_footer", &[("count", &blocklist.len())]));
+                    f
+                })
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+// Target types are stored in English, so they're translated when shown
+fn get_target_name(target_type: &str, locale: &Locale) -> String {
+    match target_type {
+        "guild" => locale.get("blocklist.target_guild"),
+        _ => locale.get("blocklist.target_user"),
+    }
+}
+
+// Users can be given as a mention or an ID, guilds only as an ID
+fn parse_target(args: &mut Args) -> Option<BlockTarget> {
+    let target_type = args.single::<String>().ok()?.to_lowercase();
+    let id = args.single::<String>().ok()?;
+
+    match target_type.as_str() {
+        "user" => {
+            let user_id = parse_username(&id).or_else(|| id.parse::<u64>().ok())?;
+
+            Some(BlockTarget::User(UserId(user_id)))
+        }
+        "guild" | "server" => Some(BlockTarget::Guild(GuildId(id.parse::<u64>().ok()?))),
+        _ => None,
+    }
+}
@@ -1,72 +1,62 @@
 use serenity::{
+    builder::CreateEmbed,
     framework::standard::{macros::command, Args, CommandResult},
     model::prelude::*,
     prelude::*,
 };
 
-use crate::JesterError;
+use crate::{
+    helpers::{command_utils, locale_helper},
+    structures::{CommandReply, Locale},
+    JesterError,
+};
 
 /// Encodes a message in base64
-/// Usage `b64encode <message>`
 #[command]
+#[usage = "<message>"]
+#[example = "hello"]
 async fn b64encode(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
-    let b64_string = base64::encode(args.rest());
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Base64 Engine");
-                e.description(format!("Encoded Message: `{}`", b64_string));
-                e
-            })
-        })
-        .await?;
-    Ok(())
+    command_utils::send_reply(ctx, msg, get_b64_encode_reply(args.rest(), &locale)).await
 }
 
-/// Decodes a message in base64
-/// Usage `b64encode <message>`
+/// Decodes a base64 message
 #[command]
+#[usage = "<base64 string>"]
+#[example = "aGVsbG8="]
 async fn b64decode(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
-    let b64_bytes = match base64::decode(args.rest()) {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    command_utils::send_reply(ctx, msg, get_b64_decode_reply(args.rest(), &locale)).await
+}
+
+pub fn get_b64_encode_reply(input: &str, locale: &Locale) -> CommandReply {
+    let b64_string = base64::encode(input);
+
+    let mut eb = CreateEmbed::default();
+    eb.title(locale.get("ciphers.title"));
+    eb.description(locale.format("ciphers.encoded", &[("message", &b64_string)]));
+
+    CommandReply::Embed(eb)
+}
+
+pub fn get_b64_decode_reply(input: &str, locale: &Locale) -> CommandReply {
+    let b64_bytes = match base64::decode(input) {
         Ok(bytes) => bytes,
         Err(_error) => {
-            msg.channel_id
-                .say(ctx, JesterError::MissingError("base64 string"))
-                .await?;
-            return Ok(());
+            return CommandReply::Text(JesterError::MissingError("base64 string").localize(locale));
         }
     };
 
-    let decoded_string = String::from_utf8(b64_bytes).unwrap();
-
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Base64 Engine");
-                e.description(format!("Decoded Message: `{}`", decoded_string));
-                e
-            })
-        })
-        .await?;
-
-    Ok(())
-}
+    let decoded_string = match String::from_utf8(b64_bytes) {
+        Ok(decoded_string) => decoded_string,
+        Err(_) => return CommandReply::Text(locale.get("ciphers.invalid_text")),
+    };
 
-pub async fn cipher_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "b64encode <message>: Encodes a message in base64 \n\n",
-        "b64decode <b64 string>: Decodes a base64 message"
-    );
+    let mut eb = CreateEmbed::default();
+    eb.title(locale.get("ciphers.title"));
+    eb.description(locale.format("ciphers.decoded", &[("message", &decoded_string)]));
 
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Cipher Help");
-                e.description("Description: Encoding/Decoding messages");
-                e.field("Commands", content, false);
-                e
-            })
-        })
-        .await;
+    CommandReply::Embed(eb)
 }
@@ -0,0 +1,376 @@
+use std::{borrow::Cow, collections::HashSet};
+
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    http::AttachmentType,
+    model::prelude::*,
+    prelude::*,
+};
+
+use crate::{
+    helpers::{
+        cooldown_helper, custom_command_helper, database_helper, locale_helper,
+        permissions_helper::MODERATOR_CHECK, template_helper,
+    },
+    structures::{ExportedCommand, Locale},
+    AliasMap, CommandNameMap, ConnectionPool, CustomCommandMap, JesterError,
+};
+
+#[derive(Clone, Copy, PartialEq)]
+enum ImportMode {
+    // Existing commands are left alone
+    Skip,
+    // Existing commands get the imported response, but keep their restrictions, category, and stats
+    Merge,
+    // Existing commands are replaced entirely, except for their stats
+    Overwrite,
+}
+
+/// Uploads all of the server's custom commands as a JSON file
+#[command]
+#[checks(Moderator)]
+#[only_in(guilds)]
+async fn export(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+    let guild_id = msg.guild_id.unwrap();
+
+    let command_data = sqlx::query!(
+        "SELECT name, content, responses::text AS responses, category, allowed_roles, allowed_channels, cooldown
+            FROM commands WHERE guild_id = $1 ORDER BY name",
+        guild_id.0 as i64
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    if command_data.is_empty() {
+        msg.channel_id
+            .say(ctx, locale.get("command.export_empty"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let commands = command_data
+        .into_iter()
+        .map(|x| ExportedCommand {
+            name: x.name,
+            content: x.content,
+            responses: x
+                .responses
+                .and_then(|responses| serde_json::from_str(&responses).ok()),
+            category: x.category,
+            roles: x.allowed_roles.iter().map(|id| *id as u64).collect(),
+            channels: x.allowed_channels.iter().map(|id| *id as u64).collect(),
+            cooldown: x.cooldown.map(|seconds| seconds as u64),
+        })
+        .collect::<Vec<ExportedCommand>>();
+
+    let export = AttachmentType::Bytes {
+        data: Cow::from(serde_json::to_vec_pretty(&commands)?),
+        filename: format!("commands-{}.json", guild_id.0),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.content(locale.format("command.exported", &[("count", &commands.len())]));
+            m.add_file(export)
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Adds custom commands from an attached export file
+/// Commands that already exist are skipped, merged (new response, same restrictions), or overwritten
+#[command]
+#[usage = "(skip/merge/overwrite) <attachment>"]
+#[example = "merge"]
+#[checks(Moderator)]
+#[only_in(guilds)]
+async fn import(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let mode = match args.single::<String>().ok().as_deref() {
+        None | Some("skip") => ImportMode::Skip,
+        Some("merge") => ImportMode::Merge,
+        Some("overwrite") => ImportMode::Overwrite,
+        Some(_) => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("import mode of skip, merge or overwrite")
+                        .localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let attachment = match msg.attachments.get(0) {
+        Some(attachment) => attachment,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("attachment").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let commands =
+        match serde_json::from_slice::<Vec<ExportedCommand>>(&attachment.download().await?) {
+            Ok(commands) => commands,
+            Err(e) => {
+                msg.channel_id
+                    .say(ctx, locale.format("command.invalid_json", &[("error", &e)]))
+                    .await?;
+
+                return Ok(());
+            }
+        };
+
+    let (pool, command_names, alias_map, custom_command_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let command_names = data.get::<CommandNameMap>().cloned().unwrap();
+        let alias_map = data.get::<AliasMap>().cloned().unwrap();
+        let custom_command_map = data.get::<CustomCommandMap>().cloned().unwrap();
+
+        (pool, command_names, alias_map, custom_command_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let aliases = match alias_map.get(&guild_id) {
+        Some(aliases) => aliases.keys().cloned().collect::<HashSet<String>>(),
+        None => HashSet::new(),
+    };
+
+    let mut existing = sqlx::query!(
+        "SELECT name FROM commands WHERE guild_id = $1",
+        guild_id.0 as i64
+    )
+    .fetch_all(&pool)
+    .await?
+    .into_iter()
+    .map(|x| x.name)
+    .collect::<HashSet<String>>();
+
+    // Roles and channels from another server are dropped, since they can't exist here
+    let (guild_roles, guild_channels) = match guild_id.to_guild_cached(ctx).await {
+        Some(guild) => (
+            Some(guild.roles.keys().map(|id| id.0).collect::<HashSet<u64>>()),
+            Some(
+                guild
+                    .channels
+                    .keys()
+                    .map(|id| id.0)
+                    .collect::<HashSet<u64>>(),
+            ),
+        ),
+        None => (None, None),
+    };
+
+    let mut created = Vec::new();
+    let mut updated = Vec::new();
+    let mut skipped = Vec::new();
+
+    // One transaction, so a failed import doesn't leave half of the commands behind
+    let result: CommandResult = async {
+        let mut transaction = pool.begin().await?;
+
+        for mut command in commands {
+            let skip_reason =
+                if command.name.is_empty() || command.name.contains(char::is_whitespace) {
+                    Some("command.import_invalid")
+                } else if command_names.contains(&command.name) {
+                    Some("command.import_hardcoded")
+                } else if aliases.contains(&command.name) {
+                    Some("command.import_alias")
+                } else if !is_valid_response(&command) {
+                    Some("command.import_invalid")
+                } else if command.cooldown.map_or(false, |seconds| {
+                    seconds == 0 || seconds > cooldown_helper::MAX_COOLDOWN_SECONDS
+                }) {
+                    Some("command.import_cooldown")
+                } else if mode == ImportMode::Skip && existing.contains(&command.name) {
+                    Some("command.import_exists")
+                } else {
+                    None
+                };
+
+            if let Some(reason) = skip_reason {
+                skipped.push(format!("{} ({})", command.name, locale.get(reason)));
+                continue;
+            }
+
+            // Categories that `command category` wouldn't accept are dropped
+            command.category = command
+                .category
+                .map(|category| category.to_lowercase())
+                .filter(|category| custom_command_helper::is_valid_category(category));
+
+            if let Some(guild_roles) = &guild_roles {
+                command.roles.retain(|id| guild_roles.contains(id));
+            }
+
+            if let Some(guild_channels) = &guild_channels {
+                command.channels.retain(|id| guild_channels.contains(id));
+            }
+
+            let responses = match &command.responses {
+                Some(responses) => Some(serde_json::to_string(responses)?),
+                None => None,
+            };
+            let roles = command
+                .roles
+                .iter()
+                .map(|id| *id as i64)
+                .collect::<Vec<i64>>();
+            let channels = command
+                .channels
+                .iter()
+                .map(|id| *id as i64)
+                .collect::<Vec<i64>>();
+            let cooldown = command.cooldown.map(|seconds| seconds as i32);
+
+            if !existing.contains(&command.name) {
+                sqlx::query!(
+                    "INSERT INTO commands(guild_id, name, content, responses, category, created_by, allowed_roles, allowed_channels, cooldown)
+                        VALUES($1, $2, $3, $4::text::jsonb, $5, $6, $7, $8, $9)",
+                    guild_id.0 as i64,
+                    command.name,
+                    command.content,
+                    responses,
+                    command.category,
+                    msg.author.id.0 as i64,
+                    &roles,
+                    &channels,
+                    cooldown
+                )
+                .execute(&mut transaction)
+                .await?;
+
+                // Later duplicates in the same file count as existing commands
+                existing.insert(command.name.to_owned());
+                created.push(command.name);
+            } else if mode == ImportMode::Merge {
+                sqlx::query!(
+                    "UPDATE commands SET content = $1, responses = $2::text::jsonb, updated_at = now()
+                        WHERE guild_id = $3 AND name = $4",
+                    command.content,
+                    responses,
+                    guild_id.0 as i64,
+                    command.name
+                )
+                .execute(&mut transaction)
+                .await?;
+
+                updated.push(command.name);
+            } else {
+                sqlx::query!(
+                    "UPDATE commands SET content = $1, responses = $2::text::jsonb, allowed_roles = $3,
+                        allowed_channels = $4, cooldown = $5, category = $8, updated_at = now()
+                        WHERE guild_id = $6 AND name = $7",
+                    command.content,
+                    responses,
+                    &roles,
+                    &channels,
+                    cooldown,
+                    guild_id.0 as i64,
+                    command.name,
+                    command.category
+                )
+                .execute(&mut transaction)
+                .await?;
+
+                updated.push(command.name);
+            }
+        }
+
+        transaction.commit().await?;
+
+        Ok(())
+    }
+    .await;
+
+    // Imports can touch every command, so the server's cache is rebuilt in one go, even on failure
+    let guild_commands = database_helper::fetch_guild_custom_commands(&pool, guild_id).await?;
+
+    if guild_commands.is_empty() {
+        custom_command_map.remove(&guild_id);
+    } else {
+        custom_command_map.insert(guild_id, guild_commands);
+    }
+
+    result?;
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("command.import_title"));
+                e.field(
+                    locale.format("command.import_created", &[("count", &created.len())]),
+                    format_names(&created, &locale),
+                    false,
+                );
+                e.field(
+                    locale.format("command.import_updated", &[("count", &updated.len())]),
+                    format_names(&updated, &locale),
+                    false,
+                );
+                e.field(
+                    locale.format("command.import_skipped", &[("count", &skipped.len())]),
+                    format_names(&skipped, &locale),
+                    false,
+                )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+// Imported commands go through the same checks as `command set`
+fn is_valid_response(command: &ExportedCommand) -> bool {
+    match (&command.content, &command.responses) {
+        (Some(content), None) => {
+            !content.trim().is_empty() && template_helper::parse_template(content).is_ok()
+        }
+        (None, Some(responses)) => custom_command_helper::validate_responses(responses).is_ok(),
+        _ => false,
+    }
+}
+
+// Embed fields can only hold 1024 characters
+fn format_names(names: &[String], locale: &Locale) -> String {
+    if names.is_empty() {
+        return locale.get("command.import_none");
+    }
+
+    let mut output = String::new();
+
+    for (i, name) in names.iter().enumerate() {
+        let line = format!("`{}`\n", name);
+
+        if output.len() + line.len() > 1000 {
+            output
+                .push_str(&locale.format("command.import_more", &[("count", &(names.len() - i))]));
+            break;
+        }
+
+        output.push_str(&line);
+    }
+
+    output
+}
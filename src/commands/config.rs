@@ -1,20 +1,67 @@
+use dashmap::DashMap;
 use serenity::{
     framework::standard::{macros::command, Args, CommandResult},
     model::prelude::*,
     prelude::*,
 };
-use std::sync::Arc;
+use sqlx::PgPool;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
 
 use crate::{
-    helpers::database_helper, helpers::permissions_helper, CommandNameMap, ConnectionPool,
-    PrefixMap, PubCreds,
+    commands::{
+        command_transfer::{EXPORT_COMMAND, IMPORT_COMMAND},
+        cooldowns::COOLDOWN_COMMAND,
+        language::LANGUAGE_COMMAND,
+        mention::MENTION_COMMAND,
+        roles::ROLES_COMMAND,
+        rules::COMMANDS_COMMAND,
+        suggestions::SUGGESTIONS_COMMAND,
+    },
+    helpers::command_utils,
+    helpers::custom_command_helper,
+    helpers::database_helper,
+    helpers::locale_helper,
+    helpers::permissions_helper::{self, ADMIN_CHECK, MODERATOR_CHECK},
+    helpers::template_helper,
+    structures::{CustomCommand, Locale, PermissionLevel},
+    AliasMap, CommandNameMap, ConnectionPool, CustomCommandMap, JesterError, PrefixMap, PubCreds,
 };
 
-/// Sets the prefix for the server using the first message argument
-/// Execute this command with no arguments to get the current prefix
+/// Server configuration for the bot
 #[command]
-#[sub_commands(restore)]
+#[sub_commands(commands, cooldown, language, mention, roles, suggestions)]
+#[only_in(guilds)]
+async fn config(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "subcommands",
+                &[(
+                    "subcommands",
+                    &"commands, cooldown, language, mention, roles, suggestions",
+                )],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Gets the server's prefixes, or replaces them with a new one
+#[command]
+#[usage = "(characters)"]
+#[example = "!"]
+#[sub_commands(restore, prefix_add, prefix_remove, prefix_list)]
+#[only_in(guilds)]
 async fn prefix(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let (pool, prefixes, default_prefix) = {
         let data = ctx.data.read().await;
 
@@ -33,60 +80,203 @@ async fn prefix(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let guild_name = msg.guild(ctx).await.unwrap().name;
 
     if args.is_empty() {
-        let cur_prefix = match prefixes.get(&guild_id) {
-            Some(prefix_guard) => prefix_guard.value().to_owned(),
-            None => default_prefix,
-        };
+        let cur_prefixes = command_utils::get_guild_prefixes(ctx, guild_id).await;
 
         msg.channel_id
             .say(
                 ctx,
-                format!("My prefix for `{}` is `{}`", guild_name, cur_prefix),
+                locale.format(
+                    "prefix.current",
+                    &[
+                        ("guild", &guild_name),
+                        ("prefixes", &format_prefixes(&cur_prefixes)),
+                    ],
+                ),
             )
             .await?;
         return Ok(());
     }
 
-    if !permissions_helper::check_permission(ctx, msg, None, false).await? {
+    if !permissions_helper::check_permission(ctx, msg, None, PermissionLevel::Moderator).await? {
         return Ok(());
     }
 
-    let new_prefix = args.single::<String>().unwrap();
+    let new_prefix = args.single_quoted::<String>().unwrap();
 
-    if new_prefix == default_prefix {
-        sqlx::query!(
-            "UPDATE guild_info SET prefix = null WHERE guild_id = $1",
-            guild_id.0 as i64
+    save_prefixes(
+        &pool,
+        &prefixes,
+        guild_id,
+        vec![new_prefix.to_owned()],
+        &default_prefix,
+    )
+    .await?;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "prefix.new",
+                &[("prefix", &new_prefix), ("guild", &guild_name)],
+            ),
         )
-        .execute(&pool)
         .await?;
 
-        prefixes.remove(&guild_id);
-    } else {
-        sqlx::query!(
-            "UPDATE guild_info SET prefix = $1 WHERE guild_id = $2",
-            new_prefix,
-            guild_id.0 as i64
+    Ok(())
+}
+
+/// Adds another prefix. Use quotes for prefixes ending with a space
+#[command("add")]
+#[usage = "<characters>"]
+#[example = "\"jester \""]
+#[checks(Moderator)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn prefix_add(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, prefixes, default_prefix) = {
+        let data = ctx.data.read().await;
+
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let prefixes = data.get::<PrefixMap>().unwrap().clone();
+        let default_prefix = data
+            .get::<PubCreds>()
+            .unwrap()
+            .get("default prefix")
+            .cloned()
+            .unwrap();
+
+        (pool, prefixes, default_prefix)
+    };
+    let guild_id = msg.guild_id.unwrap();
+
+    let new_prefix = args.single_quoted::<String>()?;
+
+    if new_prefix.is_empty() {
+        msg.channel_id
+            .say(ctx, JesterError::MissingError("prefix").localize(&locale))
+            .await?;
+
+        return Ok(());
+    }
+
+    let mut cur_prefixes = command_utils::get_guild_prefixes(ctx, guild_id).await;
+
+    if cur_prefixes.contains(&new_prefix) {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("prefix.duplicate", &[("prefix", &new_prefix)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    cur_prefixes.push(new_prefix.to_owned());
+
+    save_prefixes(&pool, &prefixes, guild_id, cur_prefixes, &default_prefix).await?;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("prefix.added", &[("prefix", &new_prefix)]),
         )
-        .execute(&pool)
         .await?;
 
-        prefixes.insert(guild_id, new_prefix.to_owned());
+    Ok(())
+}
+
+/// Removes one of the server's prefixes
+#[command("remove")]
+#[usage = "<characters>"]
+#[checks(Moderator)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn prefix_remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, prefixes, default_prefix) = {
+        let data = ctx.data.read().await;
+
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let prefixes = data.get::<PrefixMap>().unwrap().clone();
+        let default_prefix = data
+            .get::<PubCreds>()
+            .unwrap()
+            .get("default prefix")
+            .cloned()
+            .unwrap();
+
+        (pool, prefixes, default_prefix)
+    };
+    let guild_id = msg.guild_id.unwrap();
+
+    let old_prefix = args.single_quoted::<String>()?;
+
+    let mut cur_prefixes = command_utils::get_guild_prefixes(ctx, guild_id).await;
+
+    if !cur_prefixes.contains(&old_prefix) {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("prefix.not_found", &[("prefix", &old_prefix)]),
+            )
+            .await?;
+
+        return Ok(());
     }
 
+    if cur_prefixes.len() == 1 {
+        msg.channel_id
+            .say(ctx, locale.get("prefix.only_prefix"))
+            .await?;
+
+        return Ok(());
+    }
+
+    cur_prefixes.retain(|x| x != &old_prefix);
+
+    save_prefixes(&pool, &prefixes, guild_id, cur_prefixes, &default_prefix).await?;
+
     msg.channel_id
         .say(
             ctx,
-            format!("My new prefix is `{}` for `{}`!", new_prefix, guild_name),
+            locale.format("prefix.removed", &[("prefix", &old_prefix)]),
         )
         .await?;
 
     Ok(())
 }
 
+/// Lists all of the server's prefixes
+#[command("list")]
+#[only_in(guilds)]
+async fn prefix_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let cur_prefixes = command_utils::get_guild_prefixes(ctx, msg.guild_id.unwrap()).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "prefix.list",
+                &[("prefixes", &format_prefixes(&cur_prefixes))],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Resets the server's prefix back to the default one
 #[command]
-#[required_permissions("ADMINISTRATOR")]
+#[checks(Admin)]
+#[only_in(guilds)]
 async fn resetprefix(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let (pool, prefixes, default_prefix) = {
         let data = ctx.data.read().await;
 
@@ -108,7 +298,7 @@ async fn resetprefix(ctx: &Context, msg: &Message) -> CommandResult {
         prefixes.remove(&guild_id);
 
         sqlx::query!(
-            "UPDATE guild_info SET prefix = null WHERE guild_id = $1",
+            "UPDATE guild_info SET prefixes = null WHERE guild_id = $1",
             guild_id.0 as i64
         )
         .execute(&pool)
@@ -116,15 +306,63 @@ async fn resetprefix(ctx: &Context, msg: &Message) -> CommandResult {
     }
 
     msg.channel_id
-        .say(ctx, format!("Reset the prefix back to {}", default_prefix))
+        .say(
+            ctx,
+            locale.format("prefix.reset", &[("prefix", &default_prefix)]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/*
+ * Stores the guild's prefixes
+ * If only the default prefix is left, the guild is removed from the map to fall back on it
+ */
+async fn save_prefixes(
+    pool: &PgPool,
+    prefixes: &DashMap<GuildId, Vec<String>>,
+    guild_id: GuildId,
+    new_prefixes: Vec<String>,
+    default_prefix: &str,
+) -> CommandResult {
+    if new_prefixes.is_empty() || new_prefixes == [default_prefix] {
+        sqlx::query!(
+            "UPDATE guild_info SET prefixes = null WHERE guild_id = $1",
+            guild_id.0 as i64
+        )
+        .execute(pool)
         .await?;
 
+        prefixes.remove(&guild_id);
+    } else {
+        sqlx::query!(
+            "UPDATE guild_info SET prefixes = $1 WHERE guild_id = $2",
+            &new_prefixes,
+            guild_id.0 as i64
+        )
+        .execute(pool)
+        .await?;
+
+        prefixes.insert(guild_id, new_prefixes);
+    }
+
     Ok(())
 }
 
+fn format_prefixes(prefixes: &[String]) -> String {
+    prefixes
+        .iter()
+        .map(|x| format!("`{}`", x))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// Reloads every server's prefixes and custom commands from the database
 #[command]
 #[owners_only(true)]
 async fn restore(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let pool = ctx
         .data
         .read()
@@ -136,128 +374,271 @@ async fn restore(ctx: &Context, msg: &Message) -> CommandResult {
     {
         let mut data = ctx.data.write().await;
         let new_prefixes = database_helper::fetch_prefixes(&pool).await?;
+        let new_custom_commands = database_helper::fetch_custom_commands(&pool).await?;
 
         data.insert::<PrefixMap>(Arc::new(new_prefixes));
+        data.insert::<CustomCommandMap>(Arc::new(new_custom_commands));
     }
 
     msg.channel_id
-        .say(ctx, "Prefixes successfully restored!")
+        .say(ctx, locale.get("prefix.restored"))
         .await?;
 
     Ok(())
 }
 
-pub async fn prefix_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "prefix: Gets the server's current prefix \n\n",
-        "prefix (characters): Sets the server's prefix (Can be one or multiple characters)"
-    );
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Custom Prefix Help");
-                e.description("Description: Commands for custom bot prefixes");
-                e.field("Commands", content, false);
-                e
-            })
-        })
-        .await;
-}
-
-/// Custom commands for your server that output a message
-/// Usage to set: `command set <name> <content to be said>`
-/// Usage to remove: `command remove <name>`
+/// Custom commands for the server that output a message
 #[command]
-#[sub_commands(set, remove, list)]
+#[sub_commands(
+    set,
+    remove,
+    list,
+    command_info,
+    command_search,
+    command_rename,
+    command_category,
+    export,
+    import
+)]
+#[only_in(guilds)]
 async fn command(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     msg.channel_id
         .say(
             ctx,
-            "Please use one of the subcommands! (set, remove, list)",
+            locale.format(
+                "subcommands",
+                &[(
+                    "subcommands",
+                    &"set, remove, list, info, search, rename, category, export, import",
+                )],
+            ),
         )
         .await?;
 
     Ok(())
 }
 
-// Subcommand to set/update a custom command
+/// Sets a custom command. Placeholders: {user}, {args}, {arg1}, {author.name}, {channel}, {server}, {mention:arg1}, {random:a|b|c}, {count}, {time}
+/// Options before the content limit who can use it, where, and how often per user
+/// For embeds, images, and random replies, give JSON after `--embed` or attach a JSON file
 #[command]
-#[required_permissions("MANAGE_MESSAGES")]
+#[usage = "<name> (role:<role>) (channel:<channel>) (cooldown:<seconds>) <content or --embed JSON>"]
+#[example = "hello Hello {user}, welcome to {server}!"]
+#[example = "hug {author.name} hugs {mention:arg1}!"]
+#[example = "coin {random:Heads|Tails}"]
+#[example = "meme channel:#memes cooldown:30 {random:a|b|c}"]
+#[example = "rules --embed {\"embed\": {\"title\": \"Rules\", \"description\": \"Be nice!\", \"color\": \"#ff69b4\"}}"]
+#[example = "cat --embed [{\"attachments\": [\"https://example.com/cat1.png\"]}, {\"content\": \"No cats today\"}]"]
+#[checks(Moderator)]
 #[aliases("add")]
-#[min_args(2)]
+#[min_args(1)]
+#[only_in(guilds)]
 async fn set(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let command_name = args.single::<String>().unwrap();
-    let (pool, command_names) = {
+    let (pool, command_names, alias_map, custom_command_map) = {
         let data = ctx.data.read().await;
         let pool = data.get::<ConnectionPool>().cloned().unwrap();
         let command_names = data.get::<CommandNameMap>().cloned().unwrap();
+        let alias_map = data.get::<AliasMap>().cloned().unwrap();
+        let custom_command_map = data.get::<CustomCommandMap>().cloned().unwrap();
 
-        (pool, command_names)
+        (pool, command_names, alias_map, custom_command_map)
     };
 
     if command_names.contains(&command_name) {
         msg.channel_id
-            .say(
-                ctx,
-                "This command is already hardcoded! Please choose a different name!",
-            )
+            .say(ctx, locale.get("command.hardcoded"))
             .await?;
         return Ok(());
     }
 
-    let guild_id = msg.guild_id.unwrap().0 as i64;
+    let is_alias = match alias_map.get(&msg.guild_id.unwrap()) {
+        Some(aliases) => aliases.contains_key(&command_name),
+        None => false,
+    };
+
+    if is_alias {
+        msg.channel_id.say(ctx, locale.get("command.alias")).await?;
+        return Ok(());
+    }
+
+    let restrictions = match custom_command_helper::parse_restrictions(&mut args) {
+        Ok(restrictions) => restrictions,
+        Err(option) => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("command.invalid_option", &[("option", &option)]),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    // Rich responses are given as JSON after --embed or in an attached file
+    let json = if args.current() == Some("--embed") {
+        args.advance();
+
+        Some(custom_command_helper::strip_code_block(args.rest()).to_owned())
+    } else if let Some(attachment) = msg
+        .attachments
+        .iter()
+        .find(|attachment| attachment.filename.ends_with(".json"))
+    {
+        Some(String::from_utf8_lossy(&attachment.download().await?).into_owned())
+    } else {
+        None
+    };
+
+    let (content, responses) = match json {
+        Some(json) => match custom_command_helper::parse_responses(&json) {
+            Ok(responses) => (None, Some(responses)),
+            Err(e) => {
+                msg.channel_id.say(ctx, e.localize(&locale)).await?;
+                return Ok(());
+            }
+        },
+        None => {
+            let content = args.rest();
+
+            if content.trim().is_empty() {
+                msg.channel_id
+                    .say(
+                        ctx,
+                        JesterError::MissingError("command content").localize(&locale),
+                    )
+                    .await?;
+
+                return Ok(());
+            }
+
+            if let Err(e) = template_helper::parse_template(content) {
+                msg.channel_id.say(ctx, e.localize(&locale)).await?;
+                return Ok(());
+            }
+
+            (Some(content.to_owned()), None)
+        }
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let responses_json = match &responses {
+        Some(responses) => Some(serde_json::to_string(responses)?),
+        None => None,
+    };
 
     sqlx::query!(
-        "INSERT INTO commands(guild_id, name, content)
-            VALUES($1, $2, $3)
+        "INSERT INTO commands(guild_id, name, content, responses, created_by, allowed_roles, allowed_channels, cooldown)
+            VALUES($1, $2, $3, $4::text::jsonb, $5, $6, $7, $8)
             ON CONFLICT (guild_id, name)
             DO UPDATE
-            SET content = EXCLUDED.content",
-        guild_id,
+            SET content = EXCLUDED.content, responses = EXCLUDED.responses, allowed_roles = EXCLUDED.allowed_roles,
+            allowed_channels = EXCLUDED.allowed_channels, cooldown = EXCLUDED.cooldown, updated_at = now()",
+        guild_id.0 as i64,
         command_name,
-        args.rest()
+        content,
+        responses_json,
+        msg.author.id.0 as i64,
+        &restrictions.role_ids(),
+        &restrictions.channel_ids(),
+        restrictions.cooldown.map(|seconds| seconds as i32)
     )
     .execute(&pool)
     .await?;
 
+    // Editing a command keeps its use count
+    {
+        let mut commands = custom_command_map
+            .entry(guild_id)
+            .or_insert_with(HashMap::new);
+        let uses = commands
+            .get(&command_name)
+            .map_or(0, |command| command.uses);
+
+        commands.insert(
+            command_name.to_owned(),
+            CustomCommand {
+                content,
+                responses,
+                roles: restrictions.roles,
+                channels: restrictions.channels,
+                cooldown: restrictions.cooldown,
+                uses,
+            },
+        );
+    }
+
     msg.channel_id
-        .say(ctx, format!("Command `{}` sucessfully set!", command_name))
+        .say(
+            ctx,
+            locale.format("command.set", &[("name", &command_name)]),
+        )
         .await?;
 
     Ok(())
 }
 
-// Subcommand used to remove a custom command
+/// Removes an existing custom command
 #[command]
-#[required_permissions("MANAGE_MESSAGES")]
+#[usage = "<name>"]
+#[checks(Moderator)]
 #[min_args(1)]
+#[only_in(guilds)]
 async fn remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let command_name = args.single::<String>().unwrap();
-    let data = ctx.data.read().await;
-    let pool = data.get::<ConnectionPool>().unwrap();
-    let guild_id = msg.guild_id.unwrap().0 as i64;
+    let (pool, custom_command_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let custom_command_map = data.get::<CustomCommandMap>().cloned().unwrap();
+
+        (pool, custom_command_map)
+    };
+    let guild_id = msg.guild_id.unwrap();
 
     sqlx::query!(
         "DELETE FROM commands WHERE guild_id = $1 AND name = $2",
-        guild_id,
+        guild_id.0 as i64,
         command_name
     )
-    .execute(pool)
+    .execute(&pool)
     .await?;
 
+    let is_empty = match custom_command_map.get_mut(&guild_id) {
+        Some(mut commands) => {
+            commands.remove(&command_name);
+            commands.is_empty()
+        }
+        None => false,
+    };
+
+    if is_empty {
+        custom_command_map.remove(&guild_id);
+    }
+
     msg.channel_id
         .say(
             ctx,
-            format!("Command {} sucessfully deleted!", command_name),
+            locale.format("command.removed", &[("name", &command_name)]),
         )
         .await?;
 
     Ok(())
 }
 
+/// Lists the server's custom commands grouped by category, sorted by name, uses, or age
+/// Give a category to only list the commands in it
 #[command]
-async fn list(ctx: &Context, msg: &Message) -> CommandResult {
+#[usage = "(category) (name/uses/new/old)"]
+#[example = "uses"]
+#[example = "memes new"]
+#[only_in(guilds)]
+async fn list(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let pool = ctx
         .data
         .read()
@@ -266,50 +647,519 @@ async fn list(ctx: &Context, msg: &Message) -> CommandResult {
         .cloned()
         .unwrap();
     let guild_id = msg.guild_id.unwrap().0 as i64;
-    let mut command_map: Vec<String> = Vec::new();
+
+    // A sort order can be given with or without a category, so anything else is the category
+    let mut sort = "name".to_owned();
+    let mut category = None;
+
+    for arg in args.raw() {
+        let arg = arg.to_lowercase();
+
+        if custom_command_helper::SORT_ORDERS.contains(&arg.as_str()) {
+            sort = arg;
+        } else {
+            category = Some(arg);
+        }
+    }
 
     let command_data = sqlx::query!(
-        "SELECT name, content FROM commands WHERE guild_id = $1",
-        guild_id
+        "SELECT name, category, uses, to_char(created_at, 'YYYY-MM-DD') AS \"created_at!\"
+            FROM commands WHERE guild_id = $1 AND ($3::text IS NULL OR lower(category) = $3)
+            ORDER BY
+                CASE WHEN $2 = 'uses' THEN uses END DESC,
+                CASE WHEN $2 = 'new' THEN created_at END DESC,
+                CASE WHEN $2 = 'old' THEN created_at END ASC,
+                name",
+        guild_id,
+        sort,
+        category
     )
     .fetch_all(&pool)
     .await?;
 
-    for i in command_data {
-        command_map.push(i.name);
+    if command_data.is_empty() {
+        let reply = match &category {
+            Some(category) => locale.format("command.list_no_category", &[("category", category)]),
+            None => locale.get("command.list_empty"),
+        };
+
+        msg.channel_id.say(ctx, reply).await?;
+
+        return Ok(());
+    }
+
+    // Categories are listed alphabetically, with uncategorized commands last
+    let mut categories: BTreeMap<Option<String>, Vec<String>> = BTreeMap::new();
+    let total = command_data.len();
+
+    for x in command_data {
+        let entry = match sort.as_str() {
+            "uses" => locale.format("command.list_uses", &[("name", &x.name), ("uses", &x.uses)]),
+            "new" | "old" => locale.format(
+                "command.list_date",
+                &[("name", &x.name), ("date", &x.created_at)],
+            ),
+            _ => x.name,
+        };
+
+        categories
+            .entry(x.category.map(|category| category.to_lowercase()))
+            .or_insert_with(Vec::new)
+            .push(entry);
     }
 
+    let uncategorized = categories.remove(&None);
+    let mut fields = categories
+        .into_iter()
+        .filter_map(|(category, entries)| Some((category?, entries)))
+        .collect::<Vec<(String, Vec<String>)>>();
+
+    if let Some(entries) = uncategorized {
+        fields.push((locale.get("command.list_uncategorized"), entries));
+    }
+
+    // Embeds hold at most 25 fields and 6000 characters, so the rest is cut off
+    let mut shown_fields = Vec::new();
+    let mut embed_length = 0;
+
+    for (category, entries) in fields.iter().take(25) {
+        let value = format_list_field(entries, &locale);
+
+        if embed_length + category.len() + value.len() > 5500 {
+            break;
+        }
+
+        embed_length += category.len() + value.len();
+        shown_fields.push((category.to_owned(), value));
+    }
+
+    let hidden = fields.len() - shown_fields.len();
+
     msg.channel_id
         .send_message(ctx, |m| {
             m.embed(|e| {
-                e.title("Custom commands");
-                e.description(format!("```{} \n```", command_map.join(" \n")))
-            });
-
-            m
+                e.title(locale.get("command.list_title"));
+                e.fields(
+                    shown_fields
+                        .into_iter()
+                        .map(|(category, value)| (category, value, false)),
+                );
+                e.footer(|f| {
+                    if hidden > 0 {
+                        f.text(locale.format(
+                            "command.list_hidden",
+                            &[("total", &total), ("hidden", &hidden)],
+                        ))
+                    } else {
+                        f.text(locale.format("command.list_total", &[("total", &total)]))
+                    }
+                })
+            })
         })
         .await?;
 
     Ok(())
 }
 
-pub async fn command_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "set <name> <content>: Sets a new custom command, {user} is replaced with a mention \n\n",
-        "remove <name>: Removes an existing custom command \n\n",
-        "list: Lists all custom commands in the server"
+// Embed fields can only hold 1024 characters
+fn format_list_field(entries: &[String], locale: &Locale) -> String {
+    let mut output = String::from("```\n");
+
+    for (i, entry) in entries.iter().enumerate() {
+        let line = format!("{}\n", entry);
+
+        if output.len() + line.len() > 950 {
+            output.push_str("```");
+            output
+                .push_str(&locale.format("command.list_more", &[("count", &(entries.len() - i))]));
+
+            return output;
+        }
+
+        output.push_str(&line);
+    }
+
+    output.push_str("```");
+    output
+}
+
+// Mentions are joined on one line, cut off before the field's limit
+fn format_mentions(mentions: &[String], locale: &Locale) -> String {
+    let mut output = String::new();
+
+    for (i, mention) in mentions.iter().enumerate() {
+        if output.len() + mention.len() + 1 > 950 {
+            output
+                .push_str(&locale.format("command.list_more", &[("count", &(mentions.len() - i))]));
+            break;
+        }
+
+        output.push_str(mention);
+        output.push(' ');
+    }
+
+    output
+}
+
+/// Finds custom commands whose name or content contains the text
+#[command("search")]
+#[usage = "<text>"]
+#[example = "welcome"]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn command_search(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+    let guild_id = msg.guild_id.unwrap().0 as i64;
+    let query = args.rest().trim();
+
+    // Wildcards in the search text are matched literally
+    let pattern = format!(
+        "%{}%",
+        query
+            .replace('\\', "\\\\")
+            .replace('%', "\\%")
+            .replace('_', "\\_")
     );
 
-    let _ = channel_id
+    let results = sqlx::query!(
+        "SELECT name, category FROM commands
+            WHERE guild_id = $1 AND (name ILIKE $2 OR content ILIKE $2 OR responses::text ILIKE $2)
+            ORDER BY name LIMIT 26",
+        guild_id,
+        pattern
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    if results.is_empty() {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("command.search_empty", &[("query", &query)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let mut description = results
+        .iter()
+        .take(25)
+        .map(|x| match &x.category {
+            Some(category) => format!("`{}` ({})", x.name, category),
+            None => format!("`{}`", x.name),
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    if results.len() > 25 {
+        description.push_str(&format!("\n\n{}", locale.get("command.search_more")));
+    }
+
+    msg.channel_id
         .send_message(ctx, |m| {
             m.embed(|e| {
-                e.title("Custom Command Help");
-                e.description(
-                    "Description: Custom command configuration (For administrators only!)",
+                e.title(locale.format("command.search_title", &[("query", &query)]));
+                e.description(description)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Renames a custom command. Its content, restrictions, and stats stay the same
+#[command("rename")]
+#[usage = "<name> <new name>"]
+#[example = "hello welcome"]
+#[checks(Moderator)]
+#[min_args(2)]
+#[only_in(guilds)]
+async fn command_rename(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let old_name = args.single::<String>()?;
+    let new_name = args.single::<String>()?;
+
+    let (pool, command_names, alias_map, custom_command_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let command_names = data.get::<CommandNameMap>().cloned().unwrap();
+        let alias_map = data.get::<AliasMap>().cloned().unwrap();
+        let custom_command_map = data.get::<CustomCommandMap>().cloned().unwrap();
+
+        (pool, command_names, alias_map, custom_command_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    let (old_exists, new_exists) = match custom_command_map.get(&guild_id) {
+        Some(commands) => (
+            commands.contains_key(&old_name),
+            commands.contains_key(&new_name),
+        ),
+        None => (false, false),
+    };
+
+    if !old_exists {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("command.unknown", &[("name", &old_name)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let is_alias = alias_map
+        .get(&guild_id)
+        .map_or(false, |aliases| aliases.contains_key(&new_name));
+
+    let error_key = if command_names.contains(&new_name) {
+        Some("command.hardcoded")
+    } else if is_alias {
+        Some("command.alias")
+    } else if new_exists {
+        Some("command.rename_exists")
+    } else {
+        None
+    };
+
+    if let Some(error_key) = error_key {
+        msg.channel_id
+            .say(ctx, locale.format(error_key, &[("name", &new_name)]))
+            .await?;
+
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "UPDATE commands SET name = $3, updated_at = now() WHERE guild_id = $1 AND name = $2",
+        guild_id.0 as i64,
+        old_name,
+        new_name
+    )
+    .execute(&pool)
+    .await?;
+
+    if let Some(mut commands) = custom_command_map.get_mut(&guild_id) {
+        if let Some(command) = commands.remove(&old_name) {
+            commands.insert(new_name.to_owned(), command);
+        }
+    }
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "command.renamed",
+                &[("old_name", &old_name), ("new_name", &new_name)],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Puts a custom command in a category for `command list`. Leave out the category to remove it
+#[command("category")]
+#[usage = "<name> (category)"]
+#[example = "hello greetings"]
+#[example = "hello"]
+#[checks(Moderator)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn command_category(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+    let guild_id = msg.guild_id.unwrap().0 as i64;
+    let command_name = args.single::<String>()?;
+
+    let category = match args.rest().trim().to_lowercase() {
+        category if category.is_empty() => None,
+        category => Some(category),
+    };
+
+    if category.as_ref().map_or(false, |category| {
+        !custom_command_helper::is_valid_category(category)
+    }) {
+        msg.channel_id
+            .say(ctx, locale.get("command.invalid_category"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let result = sqlx::query!(
+        "UPDATE commands SET category = $3 WHERE guild_id = $1 AND name = $2",
+        guild_id,
+        command_name,
+        category
+    )
+    .execute(&pool)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("command.unknown", &[("name", &command_name)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let reply = match &category {
+        Some(category) => locale.format(
+            "command.category_set",
+            &[("name", &command_name), ("category", category)],
+        ),
+        None => locale.format("command.category_removed", &[("name", &command_name)]),
+    };
+
+    msg.channel_id.say(ctx, reply).await?;
+
+    Ok(())
+}
+
+/// Shows who made a custom command, when it was changed, and how often it's used
+#[command("info")]
+#[usage = "<name>"]
+#[example = "hello"]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn command_info(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+    let guild_id = msg.guild_id.unwrap().0 as i64;
+    let command_name = args.single::<String>()?;
+
+    let command_data = sqlx::query!(
+        "SELECT content, jsonb_pretty(responses) AS responses, category, uses, created_by, allowed_roles, allowed_channels, cooldown,
+            to_char(created_at, 'YYYY-MM-DD HH24:MI:SS TZ') AS \"created_at!\",
+            to_char(updated_at, 'YYYY-MM-DD HH24:MI:SS TZ') AS \"updated_at!\"
+            FROM commands WHERE guild_id = $1 AND name = $2",
+        guild_id,
+        command_name
+    )
+    .fetch_optional(&pool)
+    .await?;
+
+    let command_data = match command_data {
+        Some(command_data) => command_data,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("command.unknown", &[("name", &command_name)]),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    // Commands made before creators were saved don't have one
+    let creator = match command_data.created_by {
+        Some(user_id) => UserId(user_id as u64).mention().to_string(),
+        None => locale.get("command.info_unknown_creator"),
+    };
+
+    // Rich commands show the JSON they were set with
+    let content = command_data
+        .responses
+        .or(command_data.content)
+        .unwrap_or_default()
+        .replace("```", "`\u{200b}``");
+
+    // Escaping adds characters, so the limit leaves room for the code block and ellipsis
+    let content = match content.char_indices().nth(1010) {
+        Some((index, _)) => format!("{}...", &content[..index]),
+        None => content,
+    };
+
+    let roles = match command_data.allowed_roles.as_slice() {
+        [] => locale.get("command.info_anyone"),
+        roles => format_mentions(
+            &roles
+                .iter()
+                .map(|role_id| RoleId(*role_id as u64).mention().to_string())
+                .collect::<Vec<String>>(),
+            &locale,
+        ),
+    };
+
+    let channels = match command_data.allowed_channels.as_slice() {
+        [] => locale.get("command.info_anywhere"),
+        channels => format_mentions(
+            &channels
+                .iter()
+                .map(|channel_id| ChannelId(*channel_id as u64).mention().to_string())
+                .collect::<Vec<String>>(),
+            &locale,
+        ),
+    };
+
+    let cooldown = match command_data.cooldown {
+        Some(seconds) => locale.format("command.info_seconds", &[("seconds", &seconds)]),
+        None => locale.get("command.info_no_cooldown"),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.format("command.info_title", &[("name", &command_name)]));
+                e.field(locale.get("command.info_creator"), creator, true);
+                e.field(
+                    locale.get("command.info_category"),
+                    command_data
+                        .category
+                        .as_deref()
+                        .map_or_else(|| locale.get("command.list_uncategorized"), str::to_owned),
+                    true,
+                );
+                e.field(locale.get("command.info_uses"), command_data.uses, true);
+                e.field(
+                    locale.get("command.info_created"),
+                    &command_data.created_at,
+                    false,
+                );
+                e.field(
+                    locale.get("command.info_updated"),
+                    &command_data.updated_at,
+                    false,
                 );
-                e.field("Commands", content, false);
-                e
+                e.field(locale.get("command.info_roles"), roles, true);
+                e.field(locale.get("command.info_channels"), channels, true);
+                e.field(locale.get("command.info_cooldown"), cooldown, true);
+                e.field(
+                    locale.get("command.info_content"),
+                    format!("```{}```", content),
+                    false,
+                )
             })
         })
-        .await;
+        .await?;
+
+    Ok(())
 }
@@ -0,0 +1,275 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+use std::collections::HashMap;
+
+use crate::{
+    helpers::{
+        cooldown_helper::MAX_COOLDOWN_SECONDS, locale_helper, permissions_helper::ADMIN_CHECK,
+    },
+    structures::{CooldownOverride, CooldownScope},
+    CommandGroupMap, ConnectionPool, CooldownMap, JesterError,
+};
+
+// Limits are stored as integers, so they're capped well below their maximum
+const MAX_COOLDOWN_USES: u32 = 1000;
+
+/// Configures how often commands can be used
+#[command]
+#[checks(Admin)]
+#[sub_commands(cooldown_set, cooldown_reset, cooldown_list)]
+#[only_in(guilds)]
+async fn cooldown(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"set, reset, list")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Overrides a command's cooldown. Defaults to per user
+#[command("set")]
+#[usage = "<command> <uses> <seconds> (user/channel/guild)"]
+#[example = "gif 2 30 channel"]
+#[checks(Admin)]
+#[min_args(3)]
+#[only_in(guilds)]
+async fn cooldown_set(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, cooldown_map, group_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let cooldown_map = data.get::<CooldownMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+
+        (pool, cooldown_map, group_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let target = args.single::<String>()?;
+
+    let command_name = match group_map.get(&target) {
+        Some(location) => location.command.to_owned(),
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("valid command name").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let uses = match args.single::<u32>() {
+        Ok(uses) if uses > 0 && uses <= MAX_COOLDOWN_USES => uses,
+        _ => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("number of uses from 1 to 1000").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let seconds = match args.single::<u64>() {
+        Ok(seconds) if seconds > 0 && seconds <= MAX_COOLDOWN_SECONDS => seconds,
+        _ => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("number of seconds from 1 to 86400")
+                        .localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let scope = if args.is_empty() {
+        CooldownScope::User
+    } else {
+        match args.single::<String>()?.to_lowercase().as_str() {
+            "user" => CooldownScope::User,
+            "channel" => CooldownScope::Channel,
+            "guild" | "server" => CooldownScope::Guild,
+            _ => {
+                msg.channel_id
+                    .say(
+                        ctx,
+                        JesterError::MissingError("scope of user, channel or guild")
+                            .localize(&locale),
+                    )
+                    .await?;
+
+                return Ok(());
+            }
+        }
+    };
+
+    sqlx::query!(
+        "INSERT INTO command_cooldowns VALUES($1, $2, $3, $4, $5)
+            ON CONFLICT (guild_id, name)
+            DO UPDATE
+            SET scope_type = EXCLUDED.scope_type, uses = EXCLUDED.uses, seconds = EXCLUDED.seconds",
+        guild_id.0 as i64,
+        command_name,
+        scope.as_str(),
+        uses as i32,
+        seconds as i32
+    )
+    .execute(&pool)
+    .await?;
+
+    cooldown_map
+        .entry(guild_id)
+        .or_insert_with(HashMap::new)
+        .insert(
+            command_name.to_owned(),
+            CooldownOverride {
+                scope,
+                uses,
+                seconds,
+            },
+        );
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "cooldowns.set",
+                &[
+                    ("command", &command_name),
+                    ("uses", &uses),
+                    ("seconds", &seconds),
+                    (
+                        "scope",
+                        &locale.get(&format!("cooldowns.scope_{}", scope.as_str())),
+                    ),
+                ],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Goes back to the command's default cooldown
+#[command("reset")]
+#[usage = "<command>"]
+#[example = "gif"]
+#[checks(Admin)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn cooldown_reset(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, cooldown_map, group_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let cooldown_map = data.get::<CooldownMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+
+        (pool, cooldown_map, group_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let target = args.single::<String>()?;
+
+    let command_name = match group_map.get(&target) {
+        Some(location) => location.command.to_owned(),
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("valid command name").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    sqlx::query!(
+        "DELETE FROM command_cooldowns WHERE guild_id = $1 AND name = $2",
+        guild_id.0 as i64,
+        command_name
+    )
+    .execute(&pool)
+    .await?;
+
+    if let Some(mut cooldowns) = cooldown_map.get_mut(&guild_id) {
+        cooldowns.remove(&command_name);
+    }
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("cooldowns.reset", &[("command", &command_name)]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lists all cooldown overrides in the server
+#[command("list")]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn cooldown_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let cooldown_map = ctx.data.read().await.get::<CooldownMap>().cloned().unwrap();
+
+    let cooldown_string = match cooldown_map.get(&msg.guild_id.unwrap()) {
+        Some(cooldowns) => cooldowns
+            .iter()
+            .map(|(name, cooldown)| {
+                locale.format(
+                    "cooldowns.list_entry",
+                    &[
+                        ("command", name),
+                        ("uses", &cooldown.uses),
+                        ("seconds", &cooldown.seconds),
+                        (
+                            "scope",
+                            &locale.get(&format!("cooldowns.scope_{}", cooldown.scope.as_str())),
+                        ),
+                    ],
+                )
+            })
+            .collect::<String>(),
+        None => String::new(),
+    };
+
+    if cooldown_string.is_empty() {
+        msg.channel_id
+            .say(ctx, locale.get("cooldowns.none"))
+            .await?;
+
+        return Ok(());
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("cooldowns.list_title"));
+                e.description(cooldown_string)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
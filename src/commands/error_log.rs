@@ -0,0 +1,188 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+use uuid::Uuid;
+
+use crate::{helpers::locale_helper, ConnectionPool, JesterError};
+
+/// Looks up errors that happened when running commands
+#[command]
+#[owners_only(true)]
+#[sub_commands(errors_recent, errors_show)]
+async fn errors(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"recent, show")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lists the most recent command errors
+#[command("recent")]
+#[owners_only(true)]
+async fn errors_recent(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+
+    let recent_errors = sqlx::query!(
+        "SELECT error_id, command, guild_id, to_char(created_at, 'YYYY-MM-DD HH24:MI:SS TZ') AS \"created_at!\"
+            FROM command_errors ORDER BY created_at DESC LIMIT 10"
+    )
+    .fetch_all(&pool)
+    .await?;
+
+    if recent_errors.is_empty() {
+        msg.channel_id.say(ctx, locale.get("errors.none")).await?;
+
+        return Ok(());
+    }
+
+    let error_string = recent_errors
+        .iter()
+        .map(|x| {
+            let location = match x.guild_id {
+                Some(guild_id) => locale.format("errors.list_guild", &[("guild", &guild_id)]),
+                None => locale.get("errors.list_dm"),
+            };
+
+            locale.format(
+                "errors.list_entry",
+                &[
+                    ("id", &x.error_id),
+                    ("command", &x.command),
+                    ("location", &location),
+                    ("time", &x.created_at),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.color(0xff69b4);
+                e.title(locale.get("errors.list_title"));
+                e.description(error_string);
+                e.footer(|f| {
+                    f.text(locale.get("errors.list_footer"));
+                    f
+                })
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Shows the details of a command error
+#[command("show")]
+#[usage = "<id>"]
+#[owners_only(true)]
+async fn errors_show(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let error_id = match args.single::<Uuid>() {
+        Ok(error_id) => error_id,
+        Err(_) => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("valid error ID").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+
+    let error_data = sqlx::query!(
+        "SELECT guild_id, channel_id, user_id, command, arguments, error, to_char(created_at, 'YYYY-MM-DD HH24:MI:SS TZ') AS \"created_at!\"
+            FROM command_errors WHERE error_id = $1",
+        error_id
+    )
+    .fetch_optional(&pool)
+    .await?;
+
+    let error_data = match error_data {
+        Some(error_data) => error_data,
+        None => {
+            msg.channel_id
+                .say(ctx, locale.get("errors.unknown"))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let location = match error_data.guild_id {
+        Some(guild_id) => locale.format(
+            "errors.location_guild",
+            &[("guild", &guild_id), ("channel", &error_data.channel_id)],
+        ),
+        None => locale.format("errors.location_dm", &[("channel", &error_data.channel_id)]),
+    };
+
+    let arguments = if error_data.arguments.is_empty() {
+        locale.get("errors.no_arguments")
+    } else {
+        format!("```{}```", truncate(&error_data.arguments, 1000))
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.color(0xff69b4);
+                e.title(locale.format("errors.title", &[("id", &error_id)]));
+                e.description(format!("```{} \n```", truncate(&error_data.error, 2000)));
+                e.field(locale.get("errors.command"), &error_data.command, true);
+                e.field(
+                    locale.get("errors.user"),
+                    format!("<@{}>", error_data.user_id),
+                    true,
+                );
+                e.field(locale.get("errors.location"), location, false);
+                e.field(locale.get("errors.arguments"), arguments, false);
+                e.footer(|f| {
+                    f.text(
+                        locale.format("errors.happened_at", &[("time", &error_data.created_at)]),
+                    );
+                    f
+                })
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+// Embed fields have a character limit, so long errors are cut off
+fn truncate(input: &str, max_chars: usize) -> String {
+    if input.chars().count() > max_chars {
+        format!("{}...", input.chars().take(max_chars).collect::<String>())
+    } else {
+        input.to_owned()
+    }
+}
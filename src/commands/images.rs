@@ -1,13 +1,26 @@
-use crate::helpers::reaction_gifs::{add_to_cache, check_image_cache, fetch_gifs};
+use crate::{
+    helpers::{
+        command_utils, locale_helper,
+        reaction_gifs::{add_to_cache, check_image_cache, fetch_gifs},
+    },
+    structures::{CommandReply, Locale},
+};
 use rand::{prelude::StdRng, Rng, SeedableRng};
 use serenity::{
+    builder::CreateEmbed,
     framework::standard::{macros::command, Args, CommandResult},
     model::prelude::*,
     prelude::*,
 };
 
+/// Gives wholesome hugs to someone
 #[command]
+#[usage = "<mention>"]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn hug(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let is_everyone = match args.single::<String>() {
         Ok(test) => &test == "everyone" || &test == "Everyone",
         Err(_) => false,
@@ -15,48 +28,33 @@ async fn hug(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if msg.mentions.is_empty() && !is_everyone {
         msg.channel_id
-            .say(
-                ctx,
-                "You want to give a hug? Please mention who you want to hug or provide `everyone`!",
-            )
+            .say(ctx, locale.get("images.hug_missing"))
             .await?;
         return Ok(());
     }
 
-    let raw_gifs = fetch_gifs(ctx, "anime hug", 10, "medium").await?;
-    let mut rng = StdRng::from_entropy();
-
-    let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "hug".to_owned(), raw_gifs).await;
-
-    let val = rng.gen_range(0..=gifs.len() - 1);
-
-    let message = if is_everyone {
-        "Group hug!".to_owned()
-    } else if msg.mentions[0].id == msg.author.id {
-        "You hugged yourself. Cute 🙂".to_owned()
+    let target = if is_everyone {
+        None
     } else {
-        format!("{} hugs {}", msg.author.name, msg.mentions[0].name)
+        Some(&msg.mentions[0])
     };
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0xed9e2f);
-                e.description(message);
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
+    let guild_id = msg.guild_id.unwrap();
+    let reply = get_targeted_reply(ctx, &locale, guild_id, "hug", &msg.author, target).await?;
 
-    add_to_cache(ctx, guild_id, "hug".to_owned(), gifs[val].url.to_owned()).await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// Pats someone on the head
 #[command]
+#[usage = "<mention>"]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn pat(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let is_everyone = match args.single::<String>() {
         Ok(test) => (&test == "everyone" || &test == "Everyone"),
         Err(_) => false,
@@ -64,49 +62,34 @@ async fn pat(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if msg.mentions.is_empty() && !is_everyone {
         msg.channel_id
-            .say(
-                ctx,
-                "I wanna pat someone! Please mention who to pat or provide `everyone`!",
-            )
+            .say(ctx, locale.get("images.pat_missing"))
             .await?;
 
         return Ok(());
     }
 
-    let raw_gifs = fetch_gifs(ctx, "anime pat", 10, "medium").await?;
-    let mut rng = StdRng::from_entropy();
-
-    let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "pat".to_owned(), raw_gifs).await;
-
-    let val = rng.gen_range(0..=gifs.len() - 1);
-
-    let message = if is_everyone {
-        "Pats for everyone!".to_owned()
-    } else if msg.mentions[0].id == msg.author.id {
-        "You gave yourself a pat on the back!".to_owned()
+    let target = if is_everyone {
+        None
     } else {
-        format!("{} pats {}", msg.author.name, msg.mentions[0].name)
+        Some(&msg.mentions[0])
     };
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0x27e6d9);
-                e.description(message);
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
+    let guild_id = msg.guild_id.unwrap();
+    let reply = get_targeted_reply(ctx, &locale, guild_id, "pat", &msg.author, target).await?;
 
-    add_to_cache(ctx, guild_id, "pat".to_owned(), gifs[val].url.to_owned()).await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// Gives someone a slap
 #[command]
+#[usage = "<mention>"]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn slap(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let is_everyone = match args.single::<String>() {
         Ok(test) => (&test == "everyone" || &test == "Everyone"),
         Err(_) => false,
@@ -114,49 +97,34 @@ async fn slap(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if msg.mentions.is_empty() && !is_everyone {
         msg.channel_id
-            .say(
-                ctx,
-                "Wait... who do I slap again? Please mention the person or provide `everyone`!",
-            )
+            .say(ctx, locale.get("images.slap_missing"))
             .await?;
 
         return Ok(());
     }
 
-    let raw_gifs = fetch_gifs(ctx, "anime slap", 10, "medium").await?;
-    let mut rng = StdRng::from_entropy();
-
-    let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "slap".to_owned(), raw_gifs).await;
-
-    let val = rng.gen_range(0..=gifs.len() - 1);
-
-    let message = if is_everyone {
-        "You slapped everyone! Ouch... that's gotta hurt.".to_owned()
-    } else if msg.mentions[0].id == msg.author.id {
-        "You slapped yourself? Not sure if that's a good or bad thing...".to_owned()
+    let target = if is_everyone {
+        None
     } else {
-        format!("{} slaps {}", msg.author.name, msg.mentions[0].name)
+        Some(&msg.mentions[0])
     };
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0xd62929);
-                e.description(message);
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
+    let guild_id = msg.guild_id.unwrap();
+    let reply = get_targeted_reply(ctx, &locale, guild_id, "slap", &msg.author, target).await?;
 
-    add_to_cache(ctx, guild_id, "slap".to_owned(), gifs[val].url.to_owned()).await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// You already know what this is and I am shaking my head...
 #[command]
+#[usage = "<mention>"]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn kiss(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let is_everyone = match args.single::<String>() {
         Ok(test) => &test == "everyone" || &test == "Everyone",
         Err(_) => false,
@@ -164,186 +132,188 @@ async fn kiss(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if msg.mentions.is_empty() && !is_everyone {
         msg.channel_id
-            .say(
-                ctx,
-                "You want to express your feelings? Please mention who you want to kiss or provide `everyone`!",
-            )
+            .say(ctx, locale.get("images.kiss_missing"))
             .await?;
         return Ok(());
     }
 
-    let raw_gifs = fetch_gifs(ctx, "anime kiss", 10, "medium").await?;
-    let mut rng = StdRng::from_entropy();
-
-    let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "kiss".to_owned(), raw_gifs).await;
-
-    let val = rng.gen_range(0..=gifs.len() - 1);
-
-    let message = if is_everyone {
-        "A friendly kiss to everyone!".to_owned()
-    } else if msg.mentions[0].id == msg.author.id {
-        "Well... You just kissed yourself".to_owned()
+    let target = if is_everyone {
+        None
     } else {
-        format!("{} kisses {}", msg.author.name, msg.mentions[0].name)
+        Some(&msg.mentions[0])
     };
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0xffb6c1);
-                e.description(message);
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
+    let guild_id = msg.guild_id.unwrap();
+    let reply = get_targeted_reply(ctx, &locale, guild_id, "kiss", &msg.author, target).await?;
 
-    add_to_cache(ctx, guild_id, "kiss".to_owned(), gifs[val].url.to_owned()).await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// Emphasizes that you're disgusted
 #[command]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn disgust(ctx: &Context, msg: &Message) -> CommandResult {
-    let raw_gifs = fetch_gifs(ctx, "anime disgust", 10, "medium").await?;
-    let mut rng = StdRng::from_entropy();
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
 
     let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "disgust".to_owned(), raw_gifs).await;
-
-    let val = rng.gen_range(0..=gifs.len() - 1);
+    let reply = get_solo_reply(ctx, &locale, guild_id, "disgust", &msg.author).await?;
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0x50c878);
-                e.description(format!("{} is disgusted 😕", msg.author.name));
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
-
-    add_to_cache(
-        ctx,
-        guild_id,
-        "disgust".to_owned(),
-        gifs[val].url.to_owned(),
-    )
-    .await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// Emphasizes that you're crying
 #[command]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn cry(ctx: &Context, msg: &Message) -> CommandResult {
-    let raw_gifs = fetch_gifs(ctx, "anime cry", 10, "medium").await?;
-    let mut rng = StdRng::from_entropy();
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
 
     let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "cry".to_owned(), raw_gifs).await;
+    let reply = get_solo_reply(ctx, &locale, guild_id, "cry", &msg.author).await?;
 
-    let val = rng.gen_range(0..=gifs.len() - 1);
-
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0x3252e3);
-                e.description(format!("{} is crying! 😭", msg.author.name));
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
-
-    add_to_cache(ctx, guild_id, "cry".to_owned(), gifs[val].url.to_owned()).await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// Emphasizes that something is cringey
 #[command]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn cringe(ctx: &Context, msg: &Message) -> CommandResult {
-    let raw_gifs = fetch_gifs(ctx, "cringe", 10, "low").await?;
-    let mut rng = StdRng::from_entropy();
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
 
     let guild_id = msg.guild_id.unwrap();
-    let gifs = check_image_cache(ctx, guild_id, "cringe".to_owned(), raw_gifs).await;
+    let reply = get_solo_reply(ctx, &locale, guild_id, "cringe", &msg.author).await?;
 
-    let val = rng.gen_range(0..=gifs.len() - 1);
-
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0x634644);
-                e.description(format!(
-                    "{} thinks that's really cringey 😬",
-                    msg.author.name
-                ));
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
-
-    add_to_cache(ctx, guild_id, "cringe".to_owned(), gifs[val].url.to_owned()).await;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
+/// Fetches a random gif from tenor. The content filter is turned off in NSFW channels
 #[command]
+#[usage = "<search terms>"]
+#[example = "cat"]
 #[aliases("gif")]
+#[bucket = "images"]
+#[only_in(guilds)]
 async fn gifsearch(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, "Please provide a search string after the command!")
+            .say(ctx, locale.get("images.gif_missing"))
             .await?;
         return Ok(());
     }
 
-    let search_string = args.message();
+    let nsfw = msg.channel(ctx).await.unwrap().is_nsfw();
+    let reply = get_gif_reply(ctx, &locale, args.message(), nsfw).await?;
 
-    let filter = if msg.channel(ctx).await.unwrap().is_nsfw() {
-        "off"
-    } else {
-        "medium"
-    };
+    command_utils::send_reply(ctx, msg, reply).await?;
 
-    let gifs = fetch_gifs(ctx, search_string, 10, filter).await?;
+    Ok(())
+}
+
+// Search terms, content filter and embed color for each reaction command
+fn get_reaction_style(name: &str) -> (&'static str, &'static str, u32) {
+    match name {
+        "hug" => ("anime hug", "medium", 0xed9e2f),
+        "pat" => ("anime pat", "medium", 0x27e6d9),
+        "slap" => ("anime slap", "medium", 0xd62929),
+        "kiss" => ("anime kiss", "medium", 0xffb6c1),
+        "disgust" => ("anime disgust", "medium", 0x50c878),
+        "cry" => ("anime cry", "medium", 0x3252e3),
+        "cringe" => ("cringe", "low", 0x634644),
+        _ => ("anime", "medium", 0x5ed13b),
+    }
+}
+
+async fn get_reaction_reply(
+    ctx: &Context,
+    guild_id: GuildId,
+    name: &str,
+    description: String,
+) -> CommandResult<CommandReply> {
+    let (search, filter, color) = get_reaction_style(name);
+
+    let raw_gifs = fetch_gifs(ctx, search, 10, filter).await?;
     let mut rng = StdRng::from_entropy();
+
+    let gifs = check_image_cache(ctx, guild_id, name.to_owned(), raw_gifs).await;
+
     let val = rng.gen_range(0..=gifs.len() - 1);
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.color(0x5ed13b);
-                e.image(&gifs[val].media[0].get("gif").unwrap().url);
-                e
-            })
-        })
-        .await?;
+    let mut eb = CreateEmbed::default();
+    eb.color(color);
+    eb.description(description);
+    eb.image(&gifs[val].media[0].get("gif").unwrap().url);
 
-    Ok(())
+    add_to_cache(ctx, guild_id, name.to_owned(), gifs[val].url.to_owned()).await;
+
+    Ok(CommandReply::Embed(eb))
+}
+
+// A missing target means the reaction goes to everyone
+pub async fn get_targeted_reply(
+    ctx: &Context,
+    locale: &Locale,
+    guild_id: GuildId,
+    name: &str,
+    author: &User,
+    target: Option<&User>,
+) -> CommandResult<CommandReply> {
+    let description = match target {
+        None => locale.get(&format!("images.{}_everyone", name)),
+        Some(target) if target.id == author.id => locale.get(&format!("images.{}_self", name)),
+        Some(target) => locale.format(
+            &format!("images.{}", name),
+            &[("author", &author.name), ("target", &target.name)],
+        ),
+    };
+
+    get_reaction_reply(ctx, guild_id, name, description).await
+}
+
+pub async fn get_solo_reply(
+    ctx: &Context,
+    locale: &Locale,
+    guild_id: GuildId,
+    name: &str,
+    author: &User,
+) -> CommandResult<CommandReply> {
+    let description = locale.format(&format!("images.{}", name), &[("author", &author.name)]);
+
+    get_reaction_reply(ctx, guild_id, name, description).await
 }
 
-pub async fn image_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "gif: Fetches a random gif from tenor \nNote: The content filter is turned off in an NSFW channel \n\n",
-        "hug <mention>: Gives wholesome hugs to someone \n\n",
-        "pat <mention>: Pats someone on the head \n\n",
-        "slap <mention>: Give someone a slap \n\n",
-        "kiss <mention>: You already know what this is and I am shaking my head... \n\n",
-        "cry: Emphasizes that you're crying  \n\n",
-        "cringe: Emphasizes that something is cringey \n\n");
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Images/Reaction Help");
-                e.description("Description: Various commands that work with images");
-                e.field("Commands", content, false);
-                e
-            })
-        })
-        .await;
+pub async fn get_gif_reply(
+    ctx: &Context,
+    locale: &Locale,
+    search_string: &str,
+    nsfw: bool,
+) -> CommandResult<CommandReply> {
+    let filter = if nsfw { "off" } else { "medium" };
+
+    let gifs = fetch_gifs(ctx, search_string, 10, filter).await?;
+    if gifs.is_empty() {
+        return Ok(CommandReply::Text(
+            locale.format("images.gif_empty", &[("search", &search_string)]),
+        ));
+    }
+
+    let mut rng = StdRng::from_entropy();
+    let val = rng.gen_range(0..=gifs.len() - 1);
+
+    let mut eb = CreateEmbed::default();
+    eb.color(0x5ed13b);
+    eb.image(&gifs[val].media[0].get("gif").unwrap().url);
+
+    Ok(CommandReply::Embed(eb))
 }
@@ -9,8 +9,11 @@ use std::fmt::Write;
 use std::time::Duration;
 
 use crate::{
-    helpers::embed_store,
-    structures::{cmd_data::ReqwestClient, errors::JesterError, AnimeResult, MangaResult},
+    helpers::{embed_store, locale_helper},
+    structures::{
+        cmd_data::ReqwestClient, errors::JesterError, AnimeResult, CommandReply, Locale,
+        MangaResult,
+    },
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -43,13 +46,18 @@ impl ResultType {
     }
 }
 
+/// Searches for an anime's information from the title
 #[command]
+#[usage = "<title>"]
+#[example = "Cowboy Bebop"]
 async fn anime(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
             .say(
                 ctx,
-                JesterError::MissingError("anime title for me to work with!"),
+                JesterError::MissingError("anime title for me to work with!").localize(&locale),
             )
             .await?;
 
@@ -60,7 +68,7 @@ async fn anime(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
         Ok(info) => info.results,
         Err(_) => {
             msg.channel_id
-                .say(ctx, "Couldn't find your request on MAL!")
+                .say(ctx, locale.get("japan.not_found"))
                 .await?;
 
             return Ok(());
@@ -80,7 +88,7 @@ async fn anime(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
             acc
         });
 
-    let result_embed = embed_store::get_result_embed(&result_string);
+    let result_embed = embed_store::get_result_embed(&result_string, &locale);
 
     let sent_message = msg
         .channel_id
@@ -92,11 +100,11 @@ async fn anime(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
         })
         .await?;
 
-    while let Ok(value) = ask_for_results(ctx, msg).await {
+    while let Ok(value) = ask_for_results(ctx, msg, &locale).await {
         let index = value as usize;
 
         if let Some(anime) = animes.get(index - 1) {
-            let anime_embed = embed_store::get_anime_embed(anime);
+            let anime_embed = embed_store::get_anime_embed(anime, &locale);
 
             msg.channel_id
                 .send_message(ctx, |m| {
@@ -116,13 +124,18 @@ async fn anime(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     Ok(())
 }
 
+/// Searches for a manga's information from the title
 #[command]
+#[usage = "<title>"]
+#[example = "Berserk"]
 async fn manga(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
             .say(
                 ctx,
-                JesterError::MissingError("manga title for me to work with!"),
+                JesterError::MissingError("manga title for me to work with!").localize(&locale),
             )
             .await?;
 
@@ -133,7 +146,7 @@ async fn manga(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
         Ok(info) => info.results,
         Err(_) => {
             msg.channel_id
-                .say(ctx, "Couldn't find your request on MAL!")
+                .say(ctx, locale.get("japan.not_found"))
                 .await?;
 
             return Ok(());
@@ -153,7 +166,7 @@ async fn manga(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
             acc
         });
 
-    let result_embed = embed_store::get_result_embed(&result_string);
+    let result_embed = embed_store::get_result_embed(&result_string, &locale);
 
     let sent_message = msg
         .channel_id
@@ -165,11 +178,11 @@ async fn manga(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
         })
         .await?;
 
-    while let Ok(value) = ask_for_results(ctx, msg).await {
+    while let Ok(value) = ask_for_results(ctx, msg, &locale).await {
         let index = value as usize;
 
         if let Some(manga) = mangas.get(index - 1) {
-            let manga_embed = embed_store::get_manga_embed(manga);
+            let manga_embed = embed_store::get_manga_embed(manga, &locale);
 
             msg.channel_id
                 .send_message(ctx, |m| {
@@ -189,7 +202,44 @@ async fn manga(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     Ok(())
 }
 
-async fn ask_for_results(ctx: &Context, msg: &Message) -> CommandResult<isize> {
+// Slash commands can't wait for a follow-up message, so they get the best match
+pub async fn get_top_anime_reply(
+    ctx: &Context,
+    locale: &Locale,
+    title: &str,
+) -> CommandResult<CommandReply> {
+    let results = match fetch_info(ctx, "anime", title).await {
+        Ok(info) => info.results,
+        Err(_) => return Ok(CommandReply::Text(locale.get("japan.not_found"))),
+    };
+
+    match results.iter().find_map(ResultType::unwrap_anime) {
+        Some(anime) => Ok(CommandReply::Embed(embed_store::get_anime_embed(
+            anime, locale,
+        ))),
+        None => Ok(CommandReply::Text(locale.get("japan.not_found"))),
+    }
+}
+
+pub async fn get_top_manga_reply(
+    ctx: &Context,
+    locale: &Locale,
+    title: &str,
+) -> CommandResult<CommandReply> {
+    let results = match fetch_info(ctx, "manga", title).await {
+        Ok(info) => info.results,
+        Err(_) => return Ok(CommandReply::Text(locale.get("japan.not_found"))),
+    };
+
+    match results.iter().find_map(ResultType::unwrap_manga) {
+        Some(manga) => Ok(CommandReply::Embed(embed_store::get_manga_embed(
+            manga, locale,
+        ))),
+        None => Ok(CommandReply::Text(locale.get("japan.not_found"))),
+    }
+}
+
+async fn ask_for_results(ctx: &Context, msg: &Message, locale: &Locale) -> CommandResult<isize> {
     let channel_id = msg.channel_id;
 
     let result = msg
@@ -202,7 +252,10 @@ async fn ask_for_results(ctx: &Context, msg: &Message) -> CommandResult<isize> {
     match result {
         Some(recieved_msg) => {
             if recieved_msg.content == "abort" {
-                let _ = recieved_msg.channel_id.say(ctx, "Aborting...").await;
+                let _ = recieved_msg
+                    .channel_id
+                    .say(ctx, locale.get("aborting"))
+                    .await;
 
                 return Err("Aborted".into());
             }
@@ -213,7 +266,7 @@ async fn ask_for_results(ctx: &Context, msg: &Message) -> CommandResult<isize> {
             }
         }
         None => {
-            let _ = channel_id.say(ctx, "Timed out").await;
+            let _ = channel_id.say(ctx, locale.get("timed_out")).await;
 
             return Err("Timeout".into());
         }
@@ -243,21 +296,3 @@ async fn fetch_info(ctx: &Context, search_type: &str, search: &str) -> CommandRe
 
     Ok(resp)
 }
-
-pub async fn japan_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "anime <title>: Searches for an anime's information from the title \n\n",
-        "manga <title>: Searches for a manga's information from the title"
-    );
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Japan Help");
-                e.description("Description: Commands that deal with japanese media");
-                e.field("Commands", content, false);
-                e
-            })
-        })
-        .await;
-}
@@ -0,0 +1,121 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+
+use crate::{
+    helpers::{locale_helper, permissions_helper::ADMIN_CHECK},
+    ConnectionPool, GuildLanguageMap, Locales,
+};
+
+/// Shows or sets the language the bot replies with in this server
+#[command]
+#[usage = "(code/reset)"]
+#[example = "es"]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn language(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, catalog, languages) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let catalog = data.get::<Locales>().cloned().unwrap();
+        let languages = data.get::<GuildLanguageMap>().cloned().unwrap();
+
+        (pool, catalog, languages)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    if args.is_empty() {
+        let mut codes = catalog.languages.keys().collect::<Vec<&String>>();
+        codes.sort();
+
+        let available = codes
+            .iter()
+            .map(|code| {
+                let name = catalog.languages[*code]
+                    .get("language.name")
+                    .cloned()
+                    .unwrap_or_default();
+
+                format!("`{}`: {}", code, name)
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        msg.channel_id
+            .send_message(ctx, |m| {
+                m.embed(|e| {
+                    e.title(locale.get("language.title"));
+                    e.description(locale.format(
+                        "language.current",
+                        &[("language", &locale.get("language.name"))],
+                    ));
+                    e.field(locale.get("language.available"), available, false);
+                    e
+                })
+            })
+            .await?;
+
+        return Ok(());
+    }
+
+    let code = args.single::<String>()?.to_lowercase();
+
+    if code == "reset" {
+        sqlx::query!(
+            "UPDATE guild_info SET language = null WHERE guild_id = $1",
+            guild_id.0 as i64
+        )
+        .execute(&pool)
+        .await?;
+
+        languages.remove(&guild_id);
+
+        let new_locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+        msg.channel_id
+            .say(ctx, new_locale.get("language.reset"))
+            .await?;
+
+        return Ok(());
+    }
+
+    if !catalog.languages.contains_key(&code) {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("language.unknown", &[("language", &code)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    sqlx::query!(
+        "UPDATE guild_info SET language = $1 WHERE guild_id = $2",
+        code,
+        guild_id.0 as i64
+    )
+    .execute(&pool)
+    .await?;
+
+    languages.insert(guild_id, code);
+
+    let new_locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            new_locale.format(
+                "language.set",
+                &[("language", &new_locale.get("language.name"))],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
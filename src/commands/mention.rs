@@ -0,0 +1,202 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+use sqlx::PgPool;
+
+use crate::{
+    helpers::{locale_helper, permissions_helper::ADMIN_CHECK},
+    CommandGroupMap, ConnectionPool, EmergencyCommands, JesterError, MentionCommandMap,
+};
+
+/// Chooses which commands can be used by mentioning the bot, ex. `@CourtJester play`
+#[command]
+#[checks(Admin)]
+#[sub_commands(mention_add, mention_remove, mention_list)]
+#[only_in(guilds)]
+async fn mention(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"add, remove, list")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lets a command be used by mentioning the bot
+#[command("add")]
+#[usage = "<command>"]
+#[example = "play"]
+#[checks(Admin)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn mention_add(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    set_mention_command(ctx, msg, args, true).await
+}
+
+/// Stops a command from being used by mentioning the bot
+#[command("remove")]
+#[usage = "<command>"]
+#[example = "play"]
+#[checks(Admin)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn mention_remove(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    set_mention_command(ctx, msg, args, false).await
+}
+
+async fn set_mention_command(
+    ctx: &Context,
+    msg: &Message,
+    mut args: Args,
+    add: bool,
+) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, mention_map, group_map, emergency_commands) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let mention_map = data.get::<MentionCommandMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+        let emergency_commands = data.get::<EmergencyCommands>().cloned().unwrap();
+
+        (pool, mention_map, group_map, emergency_commands)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let target = args.single::<String>()?.to_lowercase();
+
+    // Aliases are stored as the command they belong to, since that's what gets checked
+    let command = match group_map.get(&target) {
+        Some(location) => location.command.to_owned(),
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("valid command name").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    if emergency_commands.contains(&command) {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("mention.emergency", &[("command", &command)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let mut mention_commands = match mention_map.get(&guild_id) {
+        Some(mention_commands) => mention_commands.value().to_owned(),
+        None => Vec::new(),
+    };
+
+    if add {
+        if !mention_commands.contains(&command) {
+            mention_commands.push(command.to_owned());
+        }
+    } else if mention_commands.contains(&command) {
+        mention_commands.retain(|x| *x != command);
+    } else {
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format("mention.unknown", &[("command", &command)]),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    save_mention_commands(&pool, guild_id, &mention_commands).await?;
+
+    if mention_commands.is_empty() {
+        mention_map.remove(&guild_id);
+    } else {
+        mention_map.insert(guild_id, mention_commands);
+    }
+
+    let key = if add {
+        "mention.added"
+    } else {
+        "mention.removed"
+    };
+
+    msg.channel_id
+        .say(ctx, locale.format(key, &[("command", &command)]))
+        .await?;
+
+    Ok(())
+}
+
+/// Lists the commands that can be used by mentioning the bot
+#[command("list")]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn mention_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (mention_map, emergency_commands) = {
+        let data = ctx.data.read().await;
+        let mention_map = data.get::<MentionCommandMap>().cloned().unwrap();
+        let emergency_commands = data.get::<EmergencyCommands>().cloned().unwrap();
+
+        (mention_map, emergency_commands)
+    };
+
+    let guild_commands = match mention_map.get(&msg.guild_id.unwrap()) {
+        Some(mention_commands) => format_commands(mention_commands.value()),
+        None => locale.get("mention.none"),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("mention.list_title"));
+                e.field(
+                    locale.get("mention.list_emergency"),
+                    format_commands(&emergency_commands),
+                    false,
+                );
+                e.field(locale.get("mention.list_guild"), guild_commands, false)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+async fn save_mention_commands(
+    pool: &PgPool,
+    guild_id: GuildId,
+    mention_commands: &[String],
+) -> CommandResult {
+    sqlx::query!(
+        "UPDATE guild_info SET mention_commands = $1 WHERE guild_id = $2",
+        mention_commands,
+        guild_id.0 as i64
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+fn format_commands(commands: &[String]) -> String {
+    commands
+        .iter()
+        .map(|command| format!("`{}`", command))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
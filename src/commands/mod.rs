@@ -1,10 +1,22 @@
+pub mod aliases;
+pub mod autoresponse;
+pub mod blocklist;
 pub mod ciphers;
+pub mod command_transfer;
 pub mod config;
+pub mod cooldowns;
+pub mod error_log;
 pub mod images;
 pub mod japan;
+pub mod language;
+pub mod mention;
 pub mod music;
 pub mod other;
+pub mod roles;
+pub mod rules;
 pub mod starboard;
+pub mod stats;
+pub mod suggestions;
 pub mod support;
 pub mod textchannel_send;
 pub mod textmod;
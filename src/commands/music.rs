@@ -6,7 +6,9 @@ use serenity::{
     framework::standard::{macros::command, Args, CommandResult},
     model::{
         channel::{Message, ReactionType},
-        id::{ChannelId, GuildId},
+        guild::Guild,
+        id::{GuildId, UserId},
+        user::User,
     },
 };
 use std::time::Duration;
@@ -14,45 +16,57 @@ use tokio::time::sleep;
 
 use crate::{
     helpers::{
-        command_utils, permissions_helper,
+        command_utils, locale_helper,
+        permissions_helper::DJ_CHECK,
         voice_utils::{self, get_voice_state},
     },
-    BotId, JesterError, Lavalink, PermissionType, SpotifyClient, VoiceTimerMap,
+    structures::CommandReply,
+    BotId, JesterError, Lavalink, SpotifyClient, VoiceTimerMap,
 };
 
+/// Plays the specified track, or adds it to the queue
 #[command]
+#[usage = "<URL or search keywords>"]
+#[example = "never gonna give you up"]
 #[aliases("p")]
+#[bucket = "music"]
+#[only_in(guilds)]
 async fn play(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     let guild = msg.guild(ctx).await.unwrap();
-    let guild_id = msg.guild_id.unwrap();
+
+    let reply = play_internal(ctx, &guild, &msg.author, args.message()).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn play_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author: &User,
+    args_message: &str,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
 
     let bot_id = ctx.data.read().await.get::<BotId>().cloned().unwrap();
 
     // TODO: Doesn't auto-summon the bot if the bot isn't in the voice channel. Check if queue is empty before running
-    if guild.voice_states.contains_key(&bot_id)
-        && !get_voice_state(ctx, &guild, msg.author.id).await?
-    {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    if guild.voice_states.contains_key(&bot_id) && !get_voice_state(ctx, guild, author.id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
-    let voice_channel_id = guild
+    let voice_channel = match guild
         .voice_states
-        .get(&msg.author.id)
-        .and_then(|voice_state| voice_state.channel_id);
-
-    let voice_channel = voice_channel_id.unwrap();
+        .get(&author.id)
+        .and_then(|voice_state| voice_state.channel_id)
+    {
+        Some(voice_channel) => voice_channel,
+        None => return Ok(CommandReply::Text(locale.get("music.voice_state"))),
+    };
 
-    if args.is_empty() {
-        msg.channel_id
-            .say(ctx, "Please enter a track URL after the command!")
-            .await?;
-        return Ok(());
+    if args_message.is_empty() {
+        return Ok(CommandReply::Text(locale.get("music.missing_track")));
     }
 
     let manager = songbird::get(ctx).await.unwrap();
@@ -65,7 +79,7 @@ async fn play(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
         .unwrap();
 
     if manager.get(guild_id).is_none() {
-        voice_utils::join_voice_internal(ctx, msg, voice_channel).await?;
+        voice_utils::join_voice_internal(ctx, guild_id, voice_channel).await?;
     }
 
     if voice_timer_map.contains_key(&guild_id) {
@@ -75,26 +89,20 @@ async fn play(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
         voice_timer_map.remove(&guild_id);
     }
 
-    let args_message = args.message();
-
     let query = if args_message.contains("https://open.spotify.com") {
         let track_id = match args_message.rsplit('/').next() {
             Some(id) => id,
             None => {
-                msg.channel_id
-                    .say(ctx, JesterError::MissingError("valid Spotify URL"))
-                    .await?;
-                return Ok(());
+                return Ok(CommandReply::Text(
+                    JesterError::MissingError("valid Spotify URL").localize(&locale),
+                ));
             }
         };
 
         match get_spotify_track_info(track_id, &ctx).await {
             Some(track_info) => track_info,
             None => {
-                msg.channel_id
-                    .say(ctx, "Couldn't find the track on spotify! Check the URL?")
-                    .await?;
-                return Ok(());
+                return Ok(CommandReply::Text(locale.get("music.spotify_not_found")));
             }
         }
     } else {
@@ -106,10 +114,7 @@ async fn play(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     let query_info = lava_client.auto_search_tracks(&query).await?;
 
     if query_info.tracks.is_empty() {
-        msg.channel_id
-            .say(ctx, "Couldn't find the video on YouTube! Check the query?")
-            .await?;
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("music.youtube_not_found")));
     }
 
     if let Err(e) = LavalinkClient::play(&lava_client, guild_id, query_info.tracks[0].clone())
@@ -124,29 +129,31 @@ async fn play(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     let mut cl = Clock::new();
     cl.set_time_ms(track_info.unwrap().length as i64);
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.content("Added to queue:");
-            m.embed(|e| {
-                e.color(0x98fb98);
-                e.title(&track_info.unwrap().title);
-                e.url(&track_info.unwrap().uri);
-                e.field("Uploader", &track_info.unwrap().author, true);
-                e.field("Length", cl.get_time(), true);
-                e.footer(|f| {
-                    f.text(format!("Requested by {}", msg.author.name));
-                    f
-                })
-            })
-        })
-        .await?;
+    let mut eb = CreateEmbed::default();
+    eb.color(0x98fb98);
+    eb.author(|a| {
+        a.name(locale.get("music.added"));
+        a
+    });
+    eb.title(&track_info.unwrap().title);
+    eb.url(&track_info.unwrap().uri);
+    eb.field(
+        locale.get("music.uploader"),
+        &track_info.unwrap().author,
+        true,
+    );
+    eb.field(locale.get("music.length"), cl.get_time(), true);
+    eb.footer(|f| {
+        f.text(locale.format("music.requested_by", &[("user", &author.name)]));
+        f
+    });
 
     let ctx_clone = ctx.clone();
     tokio::spawn(async move {
         queue_checker(ctx_clone, guild_id).await;
     });
 
-    Ok(())
+    Ok(CommandReply::Embed(eb))
 }
 
 pub async fn get_spotify_track_info(track_id: &str, ctx: &Context) -> Option<String> {
@@ -165,104 +172,120 @@ pub async fn get_spotify_track_info(track_id: &str, ctx: &Context) -> Option<Str
     }
 }
 
+/// Pauses the current track
 #[command]
+#[only_in(guilds)]
 async fn pause(ctx: &Context, msg: &Message) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = pause_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn pause_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
+
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
-    if lava_client.nodes().await.contains_key(&guild_id.0) {
-        lava_client.pause(guild_id).await?;
-        msg.react(ctx, ReactionType::Unicode(String::from("⏸")))
-            .await?;
+    if !lava_client.nodes().await.contains_key(&guild_id.0) {
+        return Ok(CommandReply::Text(locale.get("music.not_connected")));
+    }
 
-        let ctx_clone = ctx.clone();
-        tokio::spawn(async move {
-            voice_utils::create_new_timer(ctx_clone, guild_id).await;
-        });
-    };
+    lava_client.pause(guild_id).await?;
 
-    Ok(())
+    let ctx_clone = ctx.clone();
+    tokio::spawn(async move {
+        voice_utils::create_new_timer(ctx_clone, guild_id).await;
+    });
+
+    Ok(CommandReply::Reaction(ReactionType::Unicode(String::from(
+        "⏸",
+    ))))
 }
 
+/// Stops the current track and empties the queue. Doesn't disconnect the bot
 #[command]
+#[only_in(guilds)]
 async fn stop(ctx: &Context, msg: &Message) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = stop_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn stop_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
+
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
     if !lava_client.nodes().await.contains_key(&guild_id.0) {
-        msg.channel_id
-            .say(
-                ctx,
-                "The bot isn't connected to a voice channel or node! Please re-run join or play!",
-            )
-            .await?;
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("music.not_connected")));
     }
 
     lava_client.skip(guild_id).await;
     lava_client.stop(guild_id).await?;
-    msg.react(ctx, ReactionType::Unicode(String::from("🛑")))
-        .await?;
 
     let ctx_clone = ctx.clone();
     tokio::spawn(async move {
         voice_utils::create_new_timer(ctx_clone, guild_id).await;
     });
 
-    Ok(())
+    Ok(CommandReply::Reaction(ReactionType::Unicode(String::from(
+        "🛑",
+    ))))
 }
 
+/// Resumes the current track
 #[command]
 #[aliases("unpause")]
+#[only_in(guilds)]
 async fn resume(ctx: &Context, msg: &Message) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = resume_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn resume_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
+
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
     if !lava_client.nodes().await.contains_key(&guild_id.0) {
-        msg.channel_id
-            .say(
-                ctx,
-                "The bot isn't connected to a voice channel or node! Please re-run join or play!",
-            )
-            .await?;
-
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("music.not_connected")));
     }
 
     let voice_timer_map = ctx
@@ -277,54 +300,55 @@ async fn resume(ctx: &Context, msg: &Message) -> CommandResult {
         future_guard.value().abort();
     }
 
-    lava_client.resume(msg.guild_id.unwrap()).await?;
-    msg.react(ctx, ReactionType::Unicode(String::from("▶")))
-        .await?;
+    lava_client.resume(guild_id).await?;
 
-    Ok(())
+    Ok(CommandReply::Reaction(ReactionType::Unicode(String::from(
+        "▶",
+    ))))
 }
 
+/// Shows the queue and what's currently playing
 #[command]
 #[aliases("q")]
+#[only_in(guilds)]
 async fn queue(ctx: &Context, msg: &Message) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = queue_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn queue_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
     let nodes = lava_client.nodes().await;
-    let node = match nodes.get(&msg.guild_id.unwrap().0) {
+    let node = match nodes.get(&guild.id.0) {
         Some(node) => node,
         None => {
-            msg.channel_id.say(ctx, "The bot isn't connected to a voice channel or node! Please re-run join or play!").await?;
-
-            return Ok(());
+            return Ok(CommandReply::Text(locale.get("music.not_connected")));
         }
     };
 
     let queue = &node.queue;
 
     if queue.is_empty() && node.now_playing.is_none() {
-        msg.channel_id
-            .say(ctx, "The queue is currently empty!")
-            .await?;
-
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("music.queue_empty")));
     }
 
     let mut eb = CreateEmbed::default();
     eb.color(0x0377fc);
-    eb.title(format!("Queue for {}", guild_id.name(ctx).await.unwrap()));
+    eb.title(locale.format("music.queue_title", &[("guild", &guild.name)]));
 
     if let Some(t) = node.now_playing.as_ref() {
         let t_info = t.track.info.as_ref();
@@ -332,7 +356,7 @@ async fn queue(ctx: &Context, msg: &Message) -> CommandResult {
         let mut cl = Clock::new();
         cl.set_time_ms(t_info.unwrap().length as i64);
         eb.field(
-            "Now Playing",
+            locale.get("music.now_playing"),
             format!(
                 "[{}]({}) | `{}`",
                 t_info.unwrap().title,
@@ -360,19 +384,10 @@ async fn queue(ctx: &Context, msg: &Message) -> CommandResult {
             ));
         }
 
-        eb.field("Next Songs", queue_string, false);
+        eb.field(locale.get("music.next_songs"), queue_string, false);
     }
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.0 = eb.0;
-                e
-            })
-        })
-        .await?;
-
-    Ok(())
+    Ok(CommandReply::Embed(eb))
 }
 
 async fn queue_checker(ctx: Context, guild_id: GuildId) {
@@ -408,108 +423,107 @@ async fn queue_checker(ctx: Context, guild_id: GuildId) {
     }
 }
 
+/// Clears the entire queue
 #[command]
 #[aliases("c")]
+#[checks(DJ)]
+#[only_in(guilds)]
 async fn clear(ctx: &Context, msg: &Message) -> CommandResult {
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = clear_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn clear_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
     let nodes = lava_client.nodes().await;
-    let mut node = match nodes.get_mut(&msg.guild_id.unwrap().0) {
+    let mut node = match nodes.get_mut(&guild.id.0) {
         Some(node) => node,
         None => {
-            msg.channel_id.say(
-                ctx, "The bot isn't connected to a voice channel or node! Please re-run join or play!").await?;
-            return Ok(());
+            return Ok(CommandReply::Text(locale.get("music.not_connected")));
         }
     };
 
-    if !permissions_helper::check_permission(ctx, msg, None, false).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                JesterError::PermissionError(PermissionType::UserPerm("manage messages")),
-            )
-            .await?;
-    } else {
-        node.queue.drain(1..);
-
-        msg.react(ctx, ReactionType::Unicode(String::from("💣")))
-            .await?;
-    }
+    node.queue.drain(1..);
 
-    Ok(())
+    Ok(CommandReply::Reaction(ReactionType::Unicode(String::from(
+        "💣",
+    ))))
 }
 
+/// Removes a specific track from the queue
 #[command]
+#[usage = "<track number>"]
+#[example = "2"]
 #[aliases("r")]
+#[only_in(guilds)]
 async fn remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
-    }
-
-    let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
+    let clear_num = match args.single::<usize>() {
+        Ok(size) => size,
+        Err(_) => {
+            msg.channel_id
+                .say(ctx, JesterError::MissingError("number").localize(&locale))
+                .await?;
 
-    let nodes = lava_client.nodes().await;
-    let mut node = match nodes.get_mut(&msg.guild_id.unwrap().0) {
-        Some(node) => node,
-        None => {
-            msg.channel_id.say(
-                ctx, "The bot isn't connected to a voice channel or node! Please re-run join or play!").await?;
             return Ok(());
         }
     };
 
-    let clear_num = match args.single::<usize>() {
-        Ok(size) => {
-            if size == 0 {
-                msg.channel_id
-                    .say(ctx, JesterError::MissingError("number greater than 0"))
-                    .await?;
+    let reply = remove_internal(ctx, &guild, msg.author.id, clear_num).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
-                return Ok(());
-            }
+    Ok(())
+}
 
-            size
-        }
-        Err(_) => {
-            msg.channel_id
-                .say(ctx, JesterError::MissingError("number"))
-                .await?;
+pub async fn remove_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+    clear_num: usize,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
+    }
 
-            return Ok(());
+    let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
+
+    let nodes = lava_client.nodes().await;
+    let mut node = match nodes.get_mut(&guild.id.0) {
+        Some(node) => node,
+        None => {
+            return Ok(CommandReply::Text(locale.get("music.not_connected")));
         }
     };
 
+    if clear_num == 0 {
+        return Ok(CommandReply::Text(
+            JesterError::MissingError("number greater than 0").localize(&locale),
+        ));
+    }
+
     let queue = &mut node.queue;
 
     let track_queue = match queue.get(clear_num) {
         Some(track_queue) => track_queue,
         None => {
-            msg.channel_id
-                .say(ctx, "This number doesn't exist in the queue!")
-                .await?;
-
-            return Ok(());
+            return Ok(CommandReply::Text(locale.get("music.queue_missing")));
         }
     };
 
@@ -517,136 +531,106 @@ async fn remove(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     queue.remove(clear_num);
 
-    msg.channel_id
-        .say(ctx, format!("Successfully removed track {}", name))
-        .await?;
-
-    Ok(())
+    Ok(CommandReply::Text(
+        locale.format("music.removed", &[("track", &name)]),
+    ))
 }
 
+/// Skips the current track. If there are no tracks in the queue, the player is stopped
 #[command]
 #[aliases("s")]
+#[only_in(guilds)]
 async fn skip(ctx: &Context, msg: &Message) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = skip_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn skip_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
+
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
     if !lava_client.nodes().await.contains_key(&guild_id.0) {
-        msg.channel_id
-            .say(
-                ctx,
-                "The bot isn't connected to a voice channel or playing anything! Please re-run join or play!",
-            )
-            .await?;
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("music.not_playing")));
     }
 
     if lava_client.skip(guild_id).await.is_some() {
         let nodes = lava_client.nodes().await;
-        let node = nodes.get(&msg.guild_id.unwrap().0).unwrap();
+        let node = nodes.get(&guild_id.0).unwrap();
 
         if node.queue.is_empty() && node.now_playing.is_none() {
             lava_client.stop(guild_id).await?;
         }
     }
 
-    msg.react(ctx, ReactionType::Unicode(String::from("⏭️")))
-        .await?;
-
-    Ok(())
+    Ok(CommandReply::Reaction(ReactionType::Unicode(String::from(
+        "⏭️",
+    ))))
 }
 
+/// Seeks in the current track using hh:mm:ss format. mm:ss is also supported
 #[command]
+#[usage = "<time>"]
+#[example = "1:30"]
+#[only_in(guilds)]
 async fn seek(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
-    }
+    let reply = seek_internal(ctx, &guild, msg.author.id, args.single::<String>().ok()).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
 
-    if args.is_empty() {
-        msg.channel_id
-            .say(ctx, "Please provide a valid number of seconds!")
-            .await?;
-        return Ok(());
+    Ok(())
+}
+
+pub async fn seek_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+    time: Option<String>,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
+
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
-    let time = match command_utils::deconstruct_time(args.single::<String>().unwrap()) {
+    let time = match time {
+        Some(time) => time,
+        None => return Ok(CommandReply::Text(locale.get("music.seek_missing"))),
+    };
+
+    let time = match command_utils::deconstruct_time(time) {
         Ok(time) => time,
         Err(e) => {
-            msg.channel_id
-                .say(
-                    ctx,
-                    JesterError::MissingError(&format!("valid amount of {}", e)),
-                )
-                .await?;
-
-            return Ok(());
+            return Ok(CommandReply::Text(
+                JesterError::MissingError(&format!("valid amount of {}", e)).localize(&locale),
+            ));
         }
     };
 
     let lava_client = ctx.data.read().await.get::<Lavalink>().cloned().unwrap();
 
     if !lava_client.nodes().await.contains_key(&guild_id.0) {
-        msg.channel_id
-            .say(
-                ctx,
-                "The bot isn't connected to a voice channel or playing anything! Please re-run join or play!",
-            )
-            .await?;
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("music.not_playing")));
     };
 
     lava_client
         .seek(guild_id, Duration::from_secs(time))
         .await?;
 
-    msg.channel_id.say(ctx, "Seeking!").await?;
-
-    Ok(())
-}
-
-pub async fn music_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "play <URL or search keywords> : Plays the specified track \n\n",
-        "pause: Pauses the current track \n\n",
-        "resume <author> <text>: Resumes the current track \nAlias: unpause \n\n",
-        "stop: Stops the current track and empties the queue. Doesn't disconnect the bot \n\n",
-        "skip: Skips the current track. If there are no tracks in the queue, the player is stopped \n\n",
-        "seek <time>: Seeks in the current track using hh:mm:ss format. mm:ss is also supported",
-        "clear (track number): either clears the entire queue, or removes a specific track",
-        "queue: See the current queue for the guild and what's playing");
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Music Help");
-                e.description("Description: Commands for playing music");
-                e.field("Commands", content, false);
-                e.footer(|f| {
-                    f.text("For more information on voice commands, please check voice help");
-                    f
-                });
-                e
-            })
-        })
-        .await;
+    Ok(CommandReply::Text(locale.get("music.seeking")))
 }
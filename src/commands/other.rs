@@ -4,9 +4,14 @@ use serenity::{
     prelude::*,
 };
 
+use crate::helpers::locale_helper;
+
+/// Checks if the bot is responding
 #[command]
 async fn ping(ctx: &Context, msg: &Message) -> CommandResult {
-    msg.channel_id.say(ctx, "Pong!").await?;
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id.say(ctx, locale.get("other.pong")).await?;
 
     Ok(())
 }
@@ -0,0 +1,249 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+    utils::parse_role,
+};
+use std::collections::HashMap;
+
+use crate::{
+    helpers::{locale_helper, permissions_helper::ADMIN_CHECK},
+    structures::{Locale, PermissionLevel},
+    ConnectionPool, JesterError, PermissionRoleMap,
+};
+
+/// Gives roles the bot's DJ, moderator, or admin permissions without any Discord permissions
+#[command]
+#[checks(Admin)]
+#[sub_commands(roles_add, roles_remove, roles_list)]
+#[only_in(guilds)]
+async fn roles(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format("subcommands", &[("subcommands", &"add, remove, list")]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lets a role use the commands of a permission level
+#[command("add")]
+#[usage = "<dj/moderator/admin> <role mention/ID>"]
+#[example = "moderator @Bot Manager"]
+#[checks(Admin)]
+#[min_args(2)]
+#[only_in(guilds)]
+async fn roles_add(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    set_role(ctx, msg, args, true).await
+}
+
+/// Takes a permission level away from a role
+#[command("remove")]
+#[usage = "<dj/moderator/admin> <role mention/ID>"]
+#[example = "moderator @Bot Manager"]
+#[checks(Admin)]
+#[min_args(2)]
+#[only_in(guilds)]
+async fn roles_remove(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    set_role(ctx, msg, args, false).await
+}
+
+async fn set_role(ctx: &Context, msg: &Message, mut args: Args, add: bool) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, role_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let role_map = data.get::<PermissionRoleMap>().cloned().unwrap();
+
+        (pool, role_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    let level = match PermissionLevel::from_name(&args.single::<String>()?.to_lowercase()) {
+        Some(level) => level,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("permission level of dj, moderator or admin")
+                        .localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let role = match parse_role_arg(ctx, guild_id, &args.single::<String>()?).await {
+        Some(role) => role,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("role mention or ID").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let level_name = locale.get(&format!("level.{}", level.as_str()));
+
+    if add {
+        sqlx::query!(
+            "INSERT INTO permission_roles VALUES($1, $2, $3) ON CONFLICT DO NOTHING",
+            guild_id.0 as i64,
+            level.as_str(),
+            role.id.0 as i64
+        )
+        .execute(&pool)
+        .await?;
+
+        let mut levels = role_map.entry(guild_id).or_insert_with(HashMap::new);
+        let roles = levels.entry(level).or_insert_with(Vec::new);
+
+        if !roles.contains(&role.id) {
+            roles.push(role.id);
+        }
+    } else {
+        let removed = match role_map.get_mut(&guild_id) {
+            Some(mut levels) => match levels.get_mut(&level) {
+                Some(roles) => {
+                    let count = roles.len();
+                    roles.retain(|x| *x != role.id);
+
+                    roles.len() != count
+                }
+                None => false,
+            },
+            None => false,
+        };
+
+        if !removed {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format(
+                        "roles.unknown",
+                        &[("role", &role.name), ("level", &level_name)],
+                    ),
+                )
+                .await?;
+
+            return Ok(());
+        }
+
+        sqlx::query!(
+            "DELETE FROM permission_roles WHERE guild_id = $1 AND level = $2 AND role_id = $3",
+            guild_id.0 as i64,
+            level.as_str(),
+            role.id.0 as i64
+        )
+        .execute(&pool)
+        .await?;
+    }
+
+    let key = if add { "roles.set" } else { "roles.removed" };
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(key, &[("role", &role.name), ("level", &level_name)]),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lists the roles for every permission level
+#[command("list")]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn roles_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let role_map = ctx
+        .data
+        .read()
+        .await
+        .get::<PermissionRoleMap>()
+        .cloned()
+        .unwrap();
+
+    let mut levels = match role_map.get(&msg.guild_id.unwrap()) {
+        Some(levels) => levels
+            .iter()
+            .filter(|(_, roles)| !roles.is_empty())
+            .map(|(level, roles)| (*level, roles.to_owned()))
+            .collect::<Vec<(PermissionLevel, Vec<RoleId>)>>(),
+        None => Vec::new(),
+    };
+
+    if levels.is_empty() {
+        msg.channel_id.say(ctx, locale.get("roles.none")).await?;
+
+        return Ok(());
+    }
+
+    levels.sort_by_key(|(level, _)| *level);
+
+    let mut role_string = String::new();
+
+    for (level, roles) in levels {
+        let mut role_names = Vec::new();
+
+        for role_id in roles {
+            role_names.push(describe_role(ctx, &locale, role_id).await);
+        }
+
+        role_string.push_str(&locale.format(
+            "roles.list_entry",
+            &[
+                ("level", &locale.get(&format!("level.{}", level.as_str()))),
+                ("roles", &role_names.join(", ")),
+            ],
+        ));
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("roles.list_title"));
+                e.description(role_string);
+                e.footer(|f| {
+                    f.text(locale.get("roles.list_footer"));
+                    f
+                })
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+// Accepts a role mention or a raw ID, as long as the role is in this guild
+async fn parse_role_arg(ctx: &Context, guild_id: GuildId, input: &str) -> Option<Role> {
+    let role_id = match parse_role(input) {
+        Some(role_id) => role_id,
+        None => input.parse::<u64>().ok()?,
+    };
+
+    let guild = guild_id.to_guild_cached(ctx).await?;
+
+    guild.roles.get(&RoleId(role_id)).cloned()
+}
+
+// Roles are described by name so listing them doesn't ping anyone
+async fn describe_role(ctx: &Context, locale: &Locale, role_id: RoleId) -> String {
+    match role_id.to_role_cached(ctx).await {
+        Some(role) => format!("`{}`", role.name),
+        None => locale.format("roles.unknown_id", &[("id", &role_id.0)]),
+    }
+}
@@ -0,0 +1,383 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+    utils::{parse_channel, parse_role},
+};
+use std::collections::HashMap;
+
+use crate::{
+    helpers::{command_utils, locale_helper, permissions_helper::ADMIN_CHECK},
+    structures::{CommandLocation, CommandRule, Locale, RuleScope},
+    CommandGroupMap, CommandRuleMap, ConnectionPool, JesterError,
+};
+
+/// Enables or disables commands and groups for the server, a channel, or a role. Channel rules override role rules, which override server-wide rules
+#[command]
+#[checks(Admin)]
+#[sub_commands(rules_enable, rules_disable, rules_reset, rules_list)]
+#[only_in(guilds)]
+async fn commands(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "subcommands",
+                &[("subcommands", &"enable, disable, reset, list")],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Enables a command or group. Use group:<name> if a group shares a command's name
+#[command("enable")]
+#[usage = "<command/group> (channel/role mention)"]
+#[example = "mock #general"]
+#[example = "group:music"]
+#[checks(Admin)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn rules_enable(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    set_rule(ctx, msg, args, true).await
+}
+
+/// Disables a command or group. Use group:<name> if a group shares a command's name
+#[command("disable")]
+#[usage = "<command/group> (channel/role mention)"]
+#[example = "mock #general"]
+#[example = "group:music"]
+#[checks(Admin)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn rules_disable(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    set_rule(ctx, msg, args, false).await
+}
+
+async fn set_rule(ctx: &Context, msg: &Message, mut args: Args, enabled: bool) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, rule_map, group_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let rule_map = data.get::<CommandRuleMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+
+        (pool, rule_map, group_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let target = args.single::<String>()?;
+
+    let (name, is_group) = match resolve_target(&group_map, &target) {
+        Some(resolved) => resolved,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("valid command or group name").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    if is_protected(&group_map, &name, is_group) {
+        msg.channel_id
+            .say(ctx, locale.get("rules.protected"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let scope = match parse_scope(&mut args) {
+        Some(scope) => scope,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("channel or role mention").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let (scope_type, scope_id) = scope.to_db(guild_id);
+
+    sqlx::query!(
+        "INSERT INTO command_rules VALUES($1, $2, $3, $4, $5, $6)
+            ON CONFLICT (guild_id, name, is_group, scope_type, scope_id)
+            DO UPDATE
+            SET enabled = EXCLUDED.enabled",
+        guild_id.0 as i64,
+        name,
+        is_group,
+        scope_type,
+        scope_id,
+        enabled
+    )
+    .execute(&pool)
+    .await?;
+
+    {
+        let mut rules = rule_map.entry(guild_id).or_insert_with(Vec::new);
+        rules.retain(|rule| {
+            !(rule.name == name && rule.is_group == is_group && rule.scope == scope)
+        });
+        rules.push(CommandRule {
+            name: name.to_owned(),
+            is_group,
+            scope,
+            enabled,
+        });
+    }
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "rules.set",
+                &[
+                    ("kind", &describe_kind(&locale, is_group)),
+                    ("name", &name),
+                    ("state", &describe_state(&locale, enabled)),
+                    ("scope", &describe_scope(ctx, &locale, scope).await),
+                ],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Removes a rule
+#[command("reset")]
+#[usage = "<command/group> (channel/role mention)"]
+#[example = "mock #general"]
+#[checks(Admin)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn rules_reset(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, rule_map, group_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let rule_map = data.get::<CommandRuleMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+
+        (pool, rule_map, group_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let target = args.single::<String>()?;
+
+    let (name, is_group) = match resolve_target(&group_map, &target) {
+        Some(resolved) => resolved,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("valid command or group name").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let scope = match parse_scope(&mut args) {
+        Some(scope) => scope,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("channel or role mention").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let (scope_type, scope_id) = scope.to_db(guild_id);
+
+    sqlx::query!(
+        "DELETE FROM command_rules
+            WHERE guild_id = $1 AND name = $2 AND is_group = $3 AND scope_type = $4 AND scope_id = $5",
+        guild_id.0 as i64,
+        name,
+        is_group,
+        scope_type,
+        scope_id
+    )
+    .execute(&pool)
+    .await?;
+
+    if let Some(mut rules) = rule_map.get_mut(&guild_id) {
+        rules.retain(|rule| {
+            !(rule.name == name && rule.is_group == is_group && rule.scope == scope)
+        });
+    }
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "rules.reset",
+                &[
+                    ("name", &name),
+                    ("scope", &describe_scope(ctx, &locale, scope).await),
+                ],
+            ),
+        )
+        .await?;
+
+    Ok(())
+}
+
+/// Lists all command rules in the server
+#[command("list")]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn rules_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let rule_map = ctx
+        .data
+        .read()
+        .await
+        .get::<CommandRuleMap>()
+        .cloned()
+        .unwrap();
+
+    let rules = match rule_map.get(&msg.guild_id.unwrap()) {
+        Some(rules_guard) => rules_guard.value().clone(),
+        None => Vec::new(),
+    };
+
+    if rules.is_empty() {
+        msg.channel_id.say(ctx, locale.get("rules.none")).await?;
+
+        return Ok(());
+    }
+
+    let mut rule_string = String::new();
+
+    for rule in rules {
+        rule_string.push_str(&locale.format(
+            "rules.list_entry",
+            &[
+                ("kind", &describe_kind(&locale, rule.is_group)),
+                ("name", &rule.name),
+                ("state", &describe_state(&locale, rule.enabled)),
+                ("scope", &describe_scope(ctx, &locale, rule.scope).await),
+            ],
+        ));
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("rules.list_title"));
+                e.description(rule_string);
+                e.footer(|f| {
+                    f.text(locale.get("rules.list_footer"));
+                    f
+                })
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/*
+ * Resolves a command (or alias) to its primary name, otherwise tries to find a group
+ * Prefixing the target with `group:` skips the command lookup for groups sharing a command's name
+ */
+fn resolve_target(
+    group_map: &HashMap<String, CommandLocation>,
+    target: &str,
+) -> Option<(String, bool)> {
+    let group_target = match target.strip_prefix("group:") {
+        Some(group_target) => group_target,
+        None => {
+            if let Some(location) = group_map.get(target) {
+                return Some((location.command.to_owned(), false));
+            }
+
+            target
+        }
+    };
+
+    let group_name = command_utils::normalize_group_name(group_target);
+
+    if group_map.values().any(|x| x.group == group_name) {
+        Some((group_name, true))
+    } else {
+        None
+    }
+}
+
+fn is_protected(group_map: &HashMap<String, CommandLocation>, name: &str, is_group: bool) -> bool {
+    let config_group = command_utils::normalize_group_name("Bot Configuration");
+
+    if is_group {
+        name == config_group
+    } else {
+        group_map
+            .get(name)
+            .map_or(false, |location| location.group == config_group)
+    }
+}
+
+fn parse_scope(args: &mut Args) -> Option<RuleScope> {
+    if args.is_empty() {
+        return Some(RuleScope::Guild);
+    }
+
+    let mention = args.single::<String>().ok()?;
+
+    if let Some(channel_id) = parse_channel(&mention) {
+        Some(RuleScope::Channel(ChannelId(channel_id)))
+    } else {
+        parse_role(&mention).map(|role_id| RuleScope::Role(RoleId(role_id)))
+    }
+}
+
+fn describe_kind(locale: &Locale, is_group: bool) -> String {
+    if is_group {
+        locale.get("rules.group")
+    } else {
+        locale.get("rules.command")
+    }
+}
+
+fn describe_state(locale: &Locale, enabled: bool) -> String {
+    if enabled {
+        locale.get("rules.enabled")
+    } else {
+        locale.get("rules.disabled")
+    }
+}
+
+// Roles are described by name so listing rules doesn't ping anyone
+async fn describe_scope(ctx: &Context, locale: &Locale, scope: RuleScope) -> String {
+    match scope {
+        RuleScope::Guild => locale.get("rules.scope_guild"),
+        RuleScope::Channel(channel_id) => {
+            locale.format("rules.scope_channel", &[("channel", &channel_id.mention())])
+        }
+        RuleScope::Role(role_id) => match role_id.to_role_cached(ctx).await {
+            Some(role) => locale.format("rules.scope_role", &[("role", &role.name)]),
+            None => locale.format("rules.scope_role_id", &[("id", &role_id.0)]),
+        },
+    }
+}
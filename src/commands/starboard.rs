@@ -3,175 +3,560 @@ use serenity::{
     framework::standard::{macros::command, Args, CommandResult, Delimiter},
     model::{
         channel::{Message, ReactionType},
-        id::ChannelId,
+        id::{ChannelId, GuildId},
     },
+    prelude::Mentionable,
     utils::parse_channel,
 };
-use sqlx::PgPool;
-use std::time::Duration;
+use std::{convert::TryFrom, time::Duration};
 
-use crate::ConnectionPool;
+use crate::{
+    helpers::{command_utils, help_helper, locale_helper, permissions_helper::MODERATOR_CHECK},
+    structures::{CommandReply, Locale, Starboard},
+    ConnectionPool, StarboardFilterMap, StarboardMap,
+};
+
+// Every reaction in a guild is checked against all of its boards
+const MAX_STARBOARDS: usize = 10;
+
+// Thresholds are stored as integers, so anything past this is rejected rather than wrapped
+const MAX_THRESHOLD: u32 = 1000;
+
+// Changes from `starboard edit` or the slash command. Unset fields keep their current value
+#[derive(Default)]
+pub struct BoardChanges {
+    pub emoji: Option<ReactionType>,
+    pub threshold: Option<u32>,
+    pub channel: Option<ChannelId>,
+}
 
+/// Manages the server's starboards. Having any board disables the quote command
 #[command]
-#[required_permissions("MANAGE_MESSAGES")]
-#[sub_commands("deactivate", "wizard", "threshold", "channel")]
+#[checks(Moderator)]
+#[sub_commands(
+    starboard_create,
+    starboard_edit,
+    starboard_delete,
+    starboard_list,
+    starboard_filter,
+    wizard
+)]
+#[only_in(guilds)]
 async fn starboard(ctx: &Context, msg: &Message) -> CommandResult {
-    starboard_help(ctx, msg.channel_id).await;
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let help_embed = help_helper::get_command_help_embed(&locale, &STARBOARD_COMMAND, "starboard");
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.0 = help_embed.0;
+                e
+            })
+        })
+        .await?;
 
     Ok(())
 }
 
-#[command]
-async fn threshold(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    let pool = ctx
-        .data
-        .read()
-        .await
-        .get::<ConnectionPool>()
-        .cloned()
-        .unwrap();
+/// Creates a board that quotes messages once they get enough reactions with its emoji
+#[command("create")]
+#[usage = "<name> <emoji> <threshold> <channel mention>"]
+#[example = "funny 😂 5 #funny"]
+#[checks(Moderator)]
+#[min_args(4)]
+#[only_in(guilds)]
+async fn starboard_create(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let name = args.single::<String>()?.to_lowercase();
+    if !is_valid_board_name(&name) {
+        msg.channel_id
+            .say(ctx, locale.get("starboard.invalid_name"))
+            .await?;
+        return Ok(());
+    }
 
-    let new_threshold = match args.single::<u32>() {
-        Ok(threshold) => threshold,
-        Err(_e) => {
+    let emoji = match parse_emoji(ctx, msg, &args.single::<String>()?).await {
+        Some(emoji) => emoji,
+        None => {
             msg.channel_id
-                .say(ctx, "Please enter a number greater than 0!")
+                .say(ctx, locale.get("starboard.invalid_emoji"))
                 .await?;
             return Ok(());
         }
     };
 
-    sqlx::query!(
-        "UPDATE guild_info SET starboard_threshold = $1 WHERE guild_id = $2",
-        new_threshold as i32,
-        msg.guild_id.unwrap().0 as i64
+    let threshold = match args.single::<u32>() {
+        Ok(threshold) if is_valid_threshold(threshold) => threshold,
+        _ => {
+            msg.channel_id
+                .say(ctx, locale.get("starboard.invalid_threshold"))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let channel_id = match parse_channel(&args.single::<String>()?) {
+        Some(channel_id) => ChannelId(channel_id),
+        None => {
+            msg.channel_id
+                .say(ctx, locale.get("starboard.missing_channel"))
+                .await?;
+            return Ok(());
+        }
+    };
+
+    let board = Starboard {
+        name,
+        emoji,
+        threshold,
+        channel_id,
+    };
+
+    let reply = create_board(ctx, &locale, msg.guild_id.unwrap(), board).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+/// Changes a board's emoji, threshold, or channel
+#[command("edit")]
+#[usage = "<name> (emoji:<emoji>) (threshold:<number>) (channel:<channel>)"]
+#[example = "funny threshold:3"]
+#[example = "cursed emoji:💀 channel:#cursed"]
+#[checks(Moderator)]
+#[min_args(2)]
+#[only_in(guilds)]
+async fn starboard_edit(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let name = args.single::<String>()?.to_lowercase();
+    let mut changes = BoardChanges::default();
+
+    for option in args.iter::<String>() {
+        let option = option?;
+
+        let valid = if let Some(emoji) = option.strip_prefix("emoji:") {
+            changes.emoji = parse_emoji(ctx, msg, emoji).await;
+            changes.emoji.is_some()
+        } else if let Some(threshold) = option.strip_prefix("threshold:") {
+            changes.threshold = threshold.parse::<u32>().ok();
+            changes.threshold.is_some()
+        } else if let Some(channel) = option.strip_prefix("channel:") {
+            changes.channel = parse_channel(channel).map(ChannelId);
+            changes.channel.is_some()
+        } else {
+            false
+        };
+
+        if !valid {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("starboard.invalid_option", &[("option", &option)]),
+                )
+                .await?;
+            return Ok(());
+        }
+    }
+
+    let reply = edit_board_internal(ctx, &locale, msg.guild_id.unwrap(), &name, changes).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+/// Deletes a board. Deleting the last board re-enables the quote command
+#[command("delete")]
+#[usage = "<name>"]
+#[example = "cursed"]
+#[checks(Moderator)]
+#[min_args(1)]
+#[only_in(guilds)]
+async fn starboard_delete(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, starboard_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let starboard_map = data.get::<StarboardMap>().cloned().unwrap();
+
+        (pool, starboard_map)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+    let name = args.single::<String>()?.to_lowercase();
+
+    // Quoted messages of the board are removed by the foreign key
+    let result = sqlx::query!(
+        "DELETE FROM starboards WHERE guild_id = $1 AND name = $2",
+        guild_id.0 as i64,
+        name
     )
     .execute(&pool)
     .await?;
 
+    if result.rows_affected() == 0 {
+        msg.channel_id
+            .say(ctx, locale.format("starboard.unknown", &[("name", &name)]))
+            .await?;
+        return Ok(());
+    }
+
+    let is_empty = match starboard_map.get_mut(&guild_id) {
+        Some(mut boards) => {
+            boards.retain(|board| board.name != name);
+            boards.is_empty()
+        }
+        None => false,
+    };
+
+    if is_empty {
+        starboard_map.remove(&guild_id);
+    }
+
     msg.channel_id
-        .say(ctx, "New threshold sucessfully set!")
+        .say(ctx, locale.format("starboard.deleted", &[("name", &name)]))
         .await?;
 
     Ok(())
 }
 
-#[command]
-async fn channel(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    let pool = ctx
+/// Lists the server's boards
+#[command("list")]
+#[only_in(guilds)]
+async fn starboard_list(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let starboard_map = ctx
         .data
         .read()
         .await
-        .get::<ConnectionPool>()
+        .get::<StarboardMap>()
         .cloned()
         .unwrap();
 
-    let test_id = args.single::<String>().unwrap();
-    let new_channel = match parse_channel(&test_id) {
-        Some(channel_id) => channel_id,
-        None => {
-            msg.channel_id.say(ctx, "Please mention a channel!").await?;
+    let boards = match starboard_map.get(&msg.guild_id.unwrap()) {
+        Some(boards) => boards.value().to_owned(),
+        None => Vec::new(),
+    };
+
+    if boards.is_empty() {
+        msg.channel_id
+            .say(ctx, locale.get("starboard.none"))
+            .await?;
+        return Ok(());
+    }
+
+    let description = boards
+        .iter()
+        .map(|board| {
+            locale.format(
+                "starboard.list_entry",
+                &[
+                    ("name", &board.name),
+                    ("emoji", &board.emoji),
+                    ("threshold", &board.threshold),
+                    ("channel", &board.channel_id.mention()),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(locale.get("starboard.list_title"));
+                e.description(description)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Shows or changes which stars count towards the server's boards
+/// `self` ignores authors starring their own messages, `bots` ignores messages from bots,
+/// and `botstars` ignores stars from bots
+#[command("filter")]
+#[usage = "(self/bots/botstars) (on/off)"]
+#[example = "self on"]
+#[checks(Moderator)]
+#[only_in(guilds)]
+async fn starboard_filter(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (pool, starboard_filters) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let starboard_filters = data.get::<StarboardFilterMap>().cloned().unwrap();
+
+        (pool, starboard_filters)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    let mut filters = starboard_filters
+        .get(&guild_id)
+        .map(|filters| *filters)
+        .unwrap_or_default();
+
+    if args.is_empty() {
+        let state = |enabled: bool| {
+            if enabled {
+                locale.get("starboard.filter_on")
+            } else {
+                locale.get("starboard.filter_off")
+            }
+        };
+
+        msg.channel_id
+            .say(
+                ctx,
+                locale.format(
+                    "starboard.filters",
+                    &[
+                        ("self", &state(filters.ignore_self)),
+                        ("bots", &state(filters.ignore_bots)),
+                        ("botstars", &state(filters.ignore_bot_stars)),
+                    ],
+                ),
+            )
+            .await?;
+
+        return Ok(());
+    }
+
+    let filter = args.single::<String>()?.to_lowercase();
+
+    let enabled = match args
+        .single::<String>()
+        .unwrap_or_default()
+        .to_lowercase()
+        .as_str()
+    {
+        "on" | "enable" => true,
+        "off" | "disable" => false,
+        _ => {
+            msg.channel_id
+                .say(ctx, locale.get("starboard.invalid_filter"))
+                .await?;
+
             return Ok(());
         }
     };
 
+    match filter.as_str() {
+        "self" => filters.ignore_self = enabled,
+        "bots" => filters.ignore_bots = enabled,
+        "botstars" => filters.ignore_bot_stars = enabled,
+        _ => {
+            msg.channel_id
+                .say(ctx, locale.get("starboard.invalid_filter"))
+                .await?;
+
+            return Ok(());
+        }
+    }
+
     sqlx::query!(
-        "INSERT INTO text_channels VALUES($1, null, null, $2)
-                ON CONFLICT (guild_id)
-                DO UPDATE SET quote_id = $2",
-        msg.guild_id.unwrap().0 as i64,
-        new_channel as i64
+        "UPDATE guild_info SET starboard_ignore_self = $1, starboard_ignore_bots = $2, starboard_ignore_bot_stars = $3
+            WHERE guild_id = $4",
+        filters.ignore_self,
+        filters.ignore_bots,
+        filters.ignore_bot_stars,
+        guild_id.0 as i64
     )
     .execute(&pool)
     .await?;
 
+    if filters.is_empty() {
+        starboard_filters.remove(&guild_id);
+    } else {
+        starboard_filters.insert(guild_id, filters);
+    }
+
+    let key = if enabled {
+        "starboard.filter_enabled"
+    } else {
+        "starboard.filter_disabled"
+    };
+
     msg.channel_id
-        .say(ctx, "New starboard channel sucessfully set!")
+        .say(ctx, locale.format(key, &[("filter", &filter)]))
         .await?;
 
     Ok(())
 }
 
-#[command]
-async fn deactivate(ctx: &Context, msg: &Message) -> CommandResult {
-    let pool = ctx
-        .data
-        .read()
-        .await
-        .get::<ConnectionPool>()
-        .cloned()
-        .unwrap();
+pub async fn edit_board_internal(
+    ctx: &Context,
+    locale: &Locale,
+    guild_id: GuildId,
+    name: &str,
+    changes: BoardChanges,
+) -> CommandResult<CommandReply> {
+    if changes
+        .threshold
+        .map_or(false, |threshold| !is_valid_threshold(threshold))
+    {
+        return Ok(CommandReply::Text(
+            locale.get("starboard.invalid_threshold"),
+        ));
+    }
 
-    let author_id = msg.author.id;
-    let channel_id = msg.channel_id;
+    let (pool, starboard_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let starboard_map = data.get::<StarboardMap>().cloned().unwrap();
 
-    let sent_message = msg
-        .channel_id
-        .say(
-            ctx,
-            "Removing the starboard re-enables quoting! You want to do this?",
-        )
-        .await?;
-    sent_message
-        .react(ctx, ReactionType::Unicode(String::from("✅")))
-        .await?;
-    sent_message
-        .react(ctx, ReactionType::Unicode(String::from("❌")))
-        .await?;
+        (pool, starboard_map)
+    };
 
-    let reaction_action = sent_message
-        .await_reaction(ctx)
-        .filter(move |reaction| {
-            reaction.user_id == Some(author_id) && reaction.channel_id == channel_id
-        })
-        .timeout(Duration::from_secs(120))
-        .await;
+    let boards = match starboard_map.get(&guild_id) {
+        Some(boards) => boards.value().to_owned(),
+        None => Vec::new(),
+    };
 
-    match reaction_action {
-        Some(action) => {
-            let reaction = action.as_inner_ref();
-            let reaction_emoji = &reaction.emoji.as_data();
+    let mut board = match boards.iter().find(|board| board.name == name) {
+        Some(board) => board.to_owned(),
+        None => {
+            return Ok(CommandReply::Text(
+                locale.format("starboard.unknown", &[("name", &name)]),
+            ))
+        }
+    };
 
-            if reaction_emoji == "✅" {
-                sqlx::query!(
-                    "UPDATE guild_info SET starboard_threshold = null WHERE guild_id = $1",
-                    msg.guild_id.unwrap().0 as i64
-                )
-                .execute(&pool)
-                .await?;
+    if let Some(emoji) = changes.emoji {
+        if let Some(other) = boards
+            .iter()
+            .find(|other| other.name != name && other.uses_emoji(&emoji))
+        {
+            return Ok(CommandReply::Text(
+                locale.format("starboard.emoji_taken", &[("name", &other.name)]),
+            ));
+        }
 
-                sqlx::query!(
-                    "UPDATE text_channels SET quote_id = null WHERE guild_id = $1",
-                    msg.guild_id.unwrap().0 as i64
-                )
-                .execute(&pool)
-                .await?;
+        board.emoji = emoji;
+    }
 
-                msg.channel_id
-                    .say(ctx, "The starboard has been deactivated")
-                    .await?;
-            } else if reaction_emoji == "❌" {
-                msg.channel_id.say(ctx, "Aborting...").await?;
-            } else {
-                msg.channel_id
-                    .say(ctx, "That's not a valid emoji! Aborting...")
-                    .await?;
-            }
-        }
-        None => {
-            msg.channel_id.say(ctx, "Timed out").await?;
+    board.threshold = changes.threshold.unwrap_or(board.threshold);
+    board.channel_id = changes.channel.unwrap_or(board.channel_id);
+
+    sqlx::query!(
+        "UPDATE starboards SET emoji = $1, threshold = $2, channel_id = $3 WHERE guild_id = $4 AND name = $5",
+        board.emoji.to_string(),
+        board.threshold as i32,
+        board.channel_id.0 as i64,
+        guild_id.0 as i64,
+        name
+    )
+    .execute(&pool)
+    .await?;
+
+    if let Some(mut boards) = starboard_map.get_mut(&guild_id) {
+        if let Some(cached) = boards.iter_mut().find(|cached| cached.name == name) {
+            *cached = board;
         }
     }
 
-    Ok(())
+    Ok(CommandReply::Text(
+        locale.format("starboard.edited", &[("name", &name)]),
+    ))
+}
+
+async fn create_board(
+    ctx: &Context,
+    locale: &Locale,
+    guild_id: GuildId,
+    board: Starboard,
+) -> CommandResult<CommandReply> {
+    let (pool, starboard_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let starboard_map = data.get::<StarboardMap>().cloned().unwrap();
+
+        (pool, starboard_map)
+    };
+
+    let boards = match starboard_map.get(&guild_id) {
+        Some(boards) => boards.value().to_owned(),
+        None => Vec::new(),
+    };
+
+    if boards.len() >= MAX_STARBOARDS {
+        return Ok(CommandReply::Text(
+            locale.format("starboard.limit", &[("limit", &MAX_STARBOARDS)]),
+        ));
+    }
+
+    if boards.iter().any(|other| other.name == board.name) {
+        return Ok(CommandReply::Text(
+            locale.format("starboard.exists", &[("name", &board.name)]),
+        ));
+    }
+
+    if let Some(other) = boards.iter().find(|other| other.uses_emoji(&board.emoji)) {
+        return Ok(CommandReply::Text(
+            locale.format("starboard.emoji_taken", &[("name", &other.name)]),
+        ));
+    }
+
+    sqlx::query!(
+        "INSERT INTO starboards(guild_id, name, emoji, threshold, channel_id) VALUES($1, $2, $3, $4, $5)",
+        guild_id.0 as i64,
+        board.name,
+        board.emoji.to_string(),
+        board.threshold as i32,
+        board.channel_id.0 as i64
+    )
+    .execute(&pool)
+    .await?;
+
+    let reply = locale.format(
+        "starboard.created",
+        &[
+            ("name", &board.name),
+            ("emoji", &board.emoji),
+            ("threshold", &board.threshold),
+            ("channel", &board.channel_id.mention()),
+        ],
+    );
+
+    starboard_map
+        .entry(guild_id)
+        .or_insert_with(Vec::new)
+        .push(board);
+
+    Ok(CommandReply::Text(reply))
+}
+
+// Board names are one word so they can be given before the other arguments
+fn is_valid_board_name(name: &str) -> bool {
+    !name.is_empty() && name.chars().count() <= 32 && !name.contains(char::is_whitespace)
+}
+
+fn is_valid_threshold(threshold: u32) -> bool {
+    threshold > 0 && threshold <= MAX_THRESHOLD
 }
 
+// Discord rejects emojis the bot can't use, so reacting to the command checks the emoji
+async fn parse_emoji(ctx: &Context, msg: &Message, emoji: &str) -> Option<ReactionType> {
+    let emoji = ReactionType::try_from(emoji).ok()?;
+
+    match msg.react(ctx, emoji.clone()).await {
+        Ok(_) => Some(emoji),
+        Err(_) => None,
+    }
+}
+
+/// Easy way to set up a new board
 #[command]
+#[checks(Moderator)]
+#[only_in(guilds)]
 async fn wizard(ctx: &Context, msg: &Message) -> CommandResult {
-    let intro_string = concat!(
-        "Welcome to starboard configuration \n",
-        "Reacting with ✅ will disable quoting on your guild!"
-    );
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let intro_string = locale.get("starboard.wizard_intro");
 
     let author_id = msg.author.id;
     let channel_id = msg.channel_id;
@@ -199,191 +584,229 @@ async fn wizard(ctx: &Context, msg: &Message) -> CommandResult {
             let reaction_emoji = &reaction.emoji.as_data();
 
             if reaction_emoji == "✅" {
-                let pool = ctx
-                    .data
-                    .read()
-                    .await
-                    .get::<ConnectionPool>()
-                    .cloned()
-                    .unwrap();
-
-                starboard_wizard_threshold(ctx, msg, &pool).await?
+                starboard_wizard_steps(ctx, msg, &locale).await?
             } else if reaction_emoji == "❌" {
-                msg.channel_id.say(ctx, "Aborting...").await?;
+                msg.channel_id.say(ctx, locale.get("aborting")).await?;
             } else {
-                msg.channel_id
-                    .say(ctx, "That's not a valid emoji! Aborting...")
-                    .await?;
+                msg.channel_id.say(ctx, locale.get("invalid_emoji")).await?;
             }
         }
         None => {
-            msg.channel_id.say(ctx, "Timed out").await?;
+            msg.channel_id.say(ctx, locale.get("timed_out")).await?;
         }
     }
 
     Ok(())
 }
 
-async fn starboard_wizard_threshold(ctx: &Context, msg: &Message, pool: &PgPool) -> CommandResult {
+// Each step returns None when the user stops answering
+async fn starboard_wizard_steps(ctx: &Context, msg: &Message, locale: &Locale) -> CommandResult {
+    let name = match starboard_wizard_name(ctx, msg, locale).await? {
+        Some(name) => name,
+        None => return Ok(()),
+    };
+
+    let threshold = match starboard_wizard_threshold(ctx, msg, locale).await? {
+        Some(threshold) => threshold,
+        None => return Ok(()),
+    };
+
+    let emoji = match starboard_wizard_emoji(ctx, msg, locale).await? {
+        Some(emoji) => emoji,
+        None => return Ok(()),
+    };
+
+    let channel_id = match starboard_wizard_channel(ctx, msg, locale).await? {
+        Some(channel_id) => channel_id,
+        None => return Ok(()),
+    };
+
+    let board = Starboard {
+        name,
+        emoji,
+        threshold,
+        channel_id,
+    };
+
+    let reply = create_board(ctx, locale, msg.guild_id.unwrap(), board).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+async fn starboard_wizard_name(
+    ctx: &Context,
+    msg: &Message,
+    locale: &Locale,
+) -> CommandResult<Option<String>> {
     msg.channel_id
-        .say(
-            ctx,
-            "Sounds good! Please enter a number greater than 0 for the starboard threshold!",
-        )
+        .say(ctx, locale.get("starboard.wizard_name"))
         .await?;
 
     let channel_id = msg.channel_id;
+    let starboard_map = ctx
+        .data
+        .read()
+        .await
+        .get::<StarboardMap>()
+        .cloned()
+        .unwrap();
 
     loop {
-        let threshold_message = msg
+        let name_message = msg
             .author
             .await_reply(ctx)
             .timeout(Duration::from_secs(120))
             .filter(move |given_msg| given_msg.channel_id == channel_id)
             .await;
 
-        match threshold_message {
+        match name_message {
             Some(message) => {
-                match message.content.parse::<u32>() {
-                    Ok(threshold) => {
-                        if threshold > 0 {
-                            sqlx::query!("UPDATE guild_info SET starboard_threshold = $1 WHERE guild_id = $2", 
-                                    threshold as i32, msg.guild_id.unwrap().0 as i64)
-                                .execute(pool).await?;
-
-                            break;
-                        } else {
-                            msg.channel_id
-                                .say(ctx, "Please enter an integer greater than 0!")
-                                .await?;
-                        }
-                    }
-                    Err(_) => {
-                        msg.channel_id
-                            .say(ctx, "Please enter an integer greater than 0!")
-                            .await?;
-                    }
+                let name = message.content.trim().to_lowercase();
+
+                let exists = starboard_map
+                    .get(&msg.guild_id.unwrap())
+                    .map_or(false, |boards| {
+                        boards.iter().any(|board| board.name == name)
+                    });
+
+                if !is_valid_board_name(&name) {
+                    msg.channel_id
+                        .say(ctx, locale.get("starboard.invalid_name"))
+                        .await?;
+                } else if exists {
+                    msg.channel_id
+                        .say(ctx, locale.format("starboard.exists", &[("name", &name)]))
+                        .await?;
+                } else {
+                    return Ok(Some(name));
                 }
             }
             None => {
-                msg.channel_id.say(ctx, "Timed out").await?;
+                msg.channel_id.say(ctx, locale.get("timed_out")).await?;
 
-                return Ok(());
+                return Ok(None);
             }
         }
     }
+}
 
-    starboard_wizard_channel(ctx, msg, pool).await?;
+async fn starboard_wizard_threshold(
+    ctx: &Context,
+    msg: &Message,
+    locale: &Locale,
+) -> CommandResult<Option<u32>> {
+    msg.channel_id
+        .say(ctx, locale.get("starboard.wizard_threshold"))
+        .await?;
 
-    Ok(())
-}
+    let channel_id = msg.channel_id;
 
-async fn starboard_wizard_channel(ctx: &Context, msg: &Message, pool: &PgPool) -> CommandResult {
-    let mut channel_check = false;
+    loop {
+        let threshold_message = msg
+            .author
+            .await_reply(ctx)
+            .timeout(Duration::from_secs(120))
+            .filter(move |given_msg| given_msg.channel_id == channel_id)
+            .await;
 
-    let row_check = sqlx::query!(
-        "SELECT EXISTS(SELECT 1 FROM text_channels WHERE guild_id = $1)",
-        msg.guild_id.unwrap().0 as i64
-    )
-    .fetch_one(pool)
-    .await?;
+        match threshold_message {
+            Some(message) => match message.content.parse::<u32>() {
+                Ok(threshold) if is_valid_threshold(threshold) => return Ok(Some(threshold)),
+                _ => {
+                    msg.channel_id
+                        .say(ctx, locale.get("starboard.wizard_invalid_threshold"))
+                        .await?;
+                }
+            },
+            None => {
+                msg.channel_id.say(ctx, locale.get("timed_out")).await?;
 
-    if row_check.exists.unwrap() {
-        let query = sqlx::query!(
-            "SELECT quote_id FROM text_channels WHERE guild_id = $1",
-            msg.guild_id.unwrap().0 as i64
-        )
-        .fetch_one(pool)
+                return Ok(None);
+            }
+        }
+    }
+}
+
+async fn starboard_wizard_emoji(
+    ctx: &Context,
+    msg: &Message,
+    locale: &Locale,
+) -> CommandResult<Option<ReactionType>> {
+    let sent_message = msg
+        .channel_id
+        .say(ctx, locale.get("starboard.wizard_emoji"))
         .await?;
 
-        if query.quote_id.is_some() {
-            channel_check = true;
-        } else {
-            channel_check = false;
-        }
-    };
+    let author_id = msg.author.id;
 
-    if channel_check {
-        let send_string = concat!(
-            "You already have a channel set up for quotes! \nIf you want to change it, run `starboard channel <mention>` \n",
-            "Enjoy your new starboard!");
-        msg.channel_id.say(ctx, send_string).await?;
-    } else {
-        msg.channel_id
-            .say(
-                ctx,
-                "Now please mention the channel you want messages sent to!",
-            )
-            .await?;
-        let channel_id = msg.channel_id;
-
-        loop {
-            let channel_message = msg
-                .author
-                .await_reply(ctx)
-                .timeout(Duration::from_secs(120))
-                .filter(move |given_msg| given_msg.channel_id == channel_id)
-                .await;
-
-            match channel_message {
-                Some(message) => {
-                    let args = Args::new(&message.content, &[Delimiter::Single(' ')]);
-                    let given_id = args.parse::<String>().unwrap();
-
-                    match parse_channel(given_id) {
-                        Some(channel_id) => {
-                            sqlx::query!(
-                                "INSERT INTO text_channels VALUES($1, null, null, $2)
-                                        ON CONFLICT (guild_id)
-                                        DO UPDATE SET quote_id = $2",
-                                msg.guild_id.unwrap().0 as i64,
-                                channel_id as i64
-                            )
-                            .execute(pool)
-                            .await?;
+    loop {
+        let reaction_action = sent_message
+            .await_reaction(ctx)
+            .timeout(Duration::from_secs(120))
+            .filter(move |reaction| reaction.user_id == Some(author_id))
+            .await;
 
-                            msg.channel_id.say(ctx, "Enjoy your new starboard!").await?;
-                            break;
-                        }
-                        None => {
-                            msg.channel_id
-                                .say(ctx, "Please mention a channel in this guild!")
-                                .await?;
-                        }
-                    }
+        match reaction_action {
+            Some(action) => {
+                let emoji = action.as_inner_ref().emoji.clone();
+
+                // The bot has to be able to use the emoji for the starboard header
+                if sent_message.react(ctx, emoji.clone()).await.is_ok() {
+                    return Ok(Some(emoji));
+                } else {
+                    msg.channel_id
+                        .say(ctx, locale.get("starboard.wizard_invalid_emoji"))
+                        .await?;
                 }
-                None => {
-                    msg.channel_id.say(ctx, "Timed out").await?;
+            }
+            None => {
+                msg.channel_id.say(ctx, locale.get("timed_out")).await?;
 
-                    return Ok(());
-                }
+                return Ok(None);
             }
         }
     }
-
-    Ok(())
 }
 
-pub async fn starboard_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "wizard: Easy way to setup the starboard \n\n",
-        "threshold: Sets the threshold for a message to appear \n\n",
-        "channel: Sets the channel where starboard embeds are sent \n\n",
-        "deactivate: Deactivates the starboard and re-enables quoting"
-    );
+async fn starboard_wizard_channel(
+    ctx: &Context,
+    msg: &Message,
+    locale: &Locale,
+) -> CommandResult<Option<ChannelId>> {
+    msg.channel_id
+        .say(ctx, locale.get("starboard.wizard_channel"))
+        .await?;
 
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Starboard Help");
-                e.description("Description: admin commands for starboarding in a discord server");
-                e.field("Commands", content, false);
-                e.footer(|f| {
-                    f.text("Enabling the starboard will disable the quote command!");
-                    f
-                });
-                e
-            })
-        })
-        .await;
+    let channel_id = msg.channel_id;
+
+    loop {
+        let channel_message = msg
+            .author
+            .await_reply(ctx)
+            .timeout(Duration::from_secs(120))
+            .filter(move |given_msg| given_msg.channel_id == channel_id)
+            .await;
+
+        match channel_message {
+            Some(message) => {
+                let args = Args::new(&message.content, &[Delimiter::Single(' ')]);
+
+                // Replies without text, ex. only an attachment, are asked again
+                match args.parse::<String>().ok().and_then(parse_channel) {
+                    Some(channel_id) => return Ok(Some(ChannelId(channel_id))),
+                    None => {
+                        msg.channel_id
+                            .say(ctx, locale.get("starboard.wizard_invalid_channel"))
+                            .await?;
+                    }
+                }
+            }
+            None => {
+                msg.channel_id.say(ctx, locale.get("timed_out")).await?;
+
+                return Ok(None);
+            }
+        }
+    }
 }
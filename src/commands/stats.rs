@@ -0,0 +1,228 @@
+use serenity::{
+    builder::CreateEmbed,
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+use sqlx::PgPool;
+
+use crate::{
+    helpers::{locale_helper, permissions_helper::ADMIN_CHECK, stats_helper},
+    structures::Locale,
+    ConnectionPool, JesterError, UsageBuffer,
+};
+
+/// Shows command usage across every server
+#[command]
+#[usage = "(day/week/month/all)"]
+#[example = "month"]
+#[owners_only(true)]
+#[sub_commands(stats_guild)]
+async fn stats(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (label, days) = match parse_window(args.single::<String>().ok()) {
+        Some(window) => window,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("time window (day/week/month/all)").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let pool = get_flushed_pool(ctx).await?;
+    let eb = get_stats_embed(&pool, &locale, None, label, days).await?;
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.0 = eb.0;
+                e
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// Shows the most used commands and most active users in the server. Defaults to the past week
+#[command("guild")]
+#[usage = "(day/week/month/all)"]
+#[example = "day"]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn stats_guild(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let (label, days) = match parse_window(args.single::<String>().ok()) {
+        Some(window) => window,
+        None => {
+            msg.channel_id
+                .say(
+                    ctx,
+                    JesterError::MissingError("time window (day/week/month/all)").localize(&locale),
+                )
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let pool = get_flushed_pool(ctx).await?;
+    let eb = get_stats_embed(&pool, &locale, msg.guild_id, label, days).await?;
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.0 = eb.0;
+                e
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+// Writes any buffered usage first so the stats include the latest commands
+async fn get_flushed_pool(ctx: &Context) -> CommandResult<PgPool> {
+    let (pool, usage_buffer) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let usage_buffer = data.get::<UsageBuffer>().cloned().unwrap();
+
+        (pool, usage_buffer)
+    };
+
+    stats_helper::flush_buffer(&pool, &usage_buffer).await?;
+
+    Ok(pool)
+}
+
+fn parse_window(input: Option<String>) -> Option<(&'static str, i32)> {
+    let window = match input {
+        Some(window) => window.to_lowercase(),
+        None => return Some(("stats.window_week", 7)),
+    };
+
+    match window.as_str() {
+        "day" => Some(("stats.window_day", 1)),
+        "week" => Some(("stats.window_week", 7)),
+        "month" => Some(("stats.window_month", 30)),
+        "all" => Some(("stats.window_all", 36500)),
+        _ => None,
+    }
+}
+
+async fn get_stats_embed(
+    pool: &PgPool,
+    locale: &Locale,
+    guild_id: Option<GuildId>,
+    label: &str,
+    days: i32,
+) -> CommandResult<CreateEmbed> {
+    let guild_id = guild_id.map(|x| x.0 as i64);
+
+    let totals = sqlx::query!(
+        "SELECT COUNT(*) AS \"uses!\", COUNT(*) FILTER (WHERE NOT success) AS \"errors!\", AVG(duration_ms)::float8 AS avg_duration
+            FROM command_usage
+            WHERE used_at > now() - make_interval(days => $1) AND ($2::int8 IS NULL OR guild_id = $2)",
+        days,
+        guild_id
+    )
+    .fetch_one(pool)
+    .await?;
+
+    let top_commands = sqlx::query!(
+        "SELECT command, COUNT(*) AS \"uses!\"
+            FROM command_usage
+            WHERE used_at > now() - make_interval(days => $1) AND ($2::int8 IS NULL OR guild_id = $2)
+            GROUP BY command ORDER BY 2 DESC LIMIT 10",
+        days,
+        guild_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let top_users = sqlx::query!(
+        "SELECT user_id, COUNT(*) AS \"uses!\"
+            FROM command_usage
+            WHERE used_at > now() - make_interval(days => $1) AND ($2::int8 IS NULL OR guild_id = $2)
+            GROUP BY user_id ORDER BY 2 DESC LIMIT 10",
+        days,
+        guild_id
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut eb = CreateEmbed::default();
+
+    eb.color(0xfda50f);
+
+    let title_key = if guild_id.is_some() {
+        "stats.guild_title"
+    } else {
+        "stats.title"
+    };
+
+    eb.title(locale.format(title_key, &[("window", &locale.get(label))]));
+
+    if totals.uses == 0 {
+        eb.description(locale.get("stats.empty"));
+
+        return Ok(eb);
+    }
+
+    eb.description(locale.format(
+        "stats.totals",
+        &[
+            ("uses", &totals.uses),
+            ("errors", &totals.errors),
+            (
+                "duration",
+                &format!("{:.0}", totals.avg_duration.unwrap_or_default()),
+            ),
+        ],
+    ));
+
+    let command_string = top_commands
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            locale.format(
+                "stats.command_entry",
+                &[
+                    ("rank", &(i + 1)),
+                    ("command", &x.command),
+                    ("uses", &x.uses),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    let user_string = top_users
+        .iter()
+        .enumerate()
+        .map(|(i, x)| {
+            locale.format(
+                "stats.user_entry",
+                &[
+                    ("rank", &(i + 1)),
+                    ("user", &format!("<@{}>", x.user_id)),
+                    ("uses", &x.uses),
+                ],
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n");
+
+    eb.field(locale.get("stats.top_commands"), command_string, true);
+    eb.field(locale.get("stats.top_users"), user_string, true);
+
+    Ok(eb)
+}
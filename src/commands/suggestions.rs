@@ -0,0 +1,77 @@
+use serenity::{
+    framework::standard::{macros::command, Args, CommandResult},
+    model::prelude::*,
+    prelude::*,
+};
+
+use crate::{
+    helpers::{locale_helper, permissions_helper::ADMIN_CHECK},
+    ConnectionPool, SuggestionOptOuts,
+};
+
+/// Shows or toggles "did you mean" suggestions for mistyped commands
+#[command]
+#[usage = "(on/off)"]
+#[example = "off"]
+#[checks(Admin)]
+#[only_in(guilds)]
+async fn suggestions(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let (pool, opt_outs) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let opt_outs = data.get::<SuggestionOptOuts>().cloned().unwrap();
+
+        (pool, opt_outs)
+    };
+
+    let guild_id = msg.guild_id.unwrap();
+
+    if args.is_empty() {
+        let key = if !opt_outs.contains(&guild_id) {
+            "suggestion.enabled"
+        } else {
+            "suggestion.disabled"
+        };
+
+        msg.channel_id.say(ctx, locale.get(key)).await?;
+
+        return Ok(());
+    }
+
+    let enabled = match args.single::<String>()?.to_lowercase().as_str() {
+        "on" | "enable" => true,
+        "off" | "disable" => false,
+        _ => {
+            msg.channel_id
+                .say(ctx, locale.get("suggestion.invalid"))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    sqlx::query!(
+        "UPDATE guild_info SET suggestions = $1 WHERE guild_id = $2",
+        enabled,
+        guild_id.0 as i64
+    )
+    .execute(&pool)
+    .await?;
+
+    if enabled {
+        opt_outs.remove(&guild_id);
+    } else {
+        opt_outs.insert(guild_id);
+    }
+
+    let key = if enabled {
+        "suggestion.turned_on"
+    } else {
+        "suggestion.turned_off"
+    };
+
+    msg.channel_id.say(ctx, locale.get(key)).await?;
+
+    Ok(())
+}
@@ -6,116 +6,97 @@ use serenity::{
 };
 
 use crate::{
-    commands::{
-        ciphers::*, config::*, images::*, japan::*, music::*, starboard::*, textchannel_send::*,
-        textmod::*, utility::*,
-    },
-    helpers::{botinfo::*, command_utils, voice_utils::*},
+    helpers::{botinfo::*, command_utils, embed_store, help_helper, locale_helper},
+    structures::{commands::MASTER_GROUP, CommandReply, Locale},
 };
 
+/// Shows every command group, or the details of a group or command
 #[command]
-async fn help(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    if args.is_empty() {
-        if command_utils::check_mention_prefix(msg) {
-            emergency_help_message(ctx, msg.channel_id).await;
-        } else {
-            default_help_message(ctx, msg.channel_id).await;
-        }
-
-        return Ok(());
-    }
-
-    let subcommand = args.single::<String>()?;
-
-    match subcommand.as_str() {
-        "prefix" => prefix_help(ctx, msg.channel_id).await,
-        "command" => command_help(ctx, msg.channel_id).await,
-        "starboard" => starboard_help(ctx, msg.channel_id).await,
-        "utilities" => utility_help(ctx, msg.channel_id).await,
-        "senders" => sender_help(ctx, msg.channel_id).await,
-        "ciphers" => cipher_help(ctx, msg.channel_id).await,
-        "text" => textmod_help(ctx, msg.channel_id).await,
-        "voice" => voice_help(ctx, msg.channel_id).await,
-        "music" => music_help(ctx, msg.channel_id).await,
-        "images" => image_help(ctx, msg.channel_id).await,
-        "japan" => japan_help(ctx, msg.channel_id).await,
-        _ => {}
-    }
+#[usage = "(group/command)"]
+#[example = "music"]
+#[example = "config cooldown set"]
+async fn help(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let groups = MASTER_GROUP.options.sub_groups;
+
+    let words = args
+        .raw()
+        .map(|word| word.to_lowercase())
+        .collect::<Vec<String>>();
+
+    let reply = if words.is_empty() && command_utils::check_mention_prefix(msg) {
+        let mention_commands = command_utils::get_mention_commands(ctx, msg.guild_id).await;
+
+        CommandReply::Embed(help_helper::get_emergency_help_embed(
+            &locale,
+            groups,
+            &mention_commands,
+        ))
+    } else {
+        get_help_reply(&locale, &words)
+    };
+
+    command_utils::send_reply(ctx, msg, reply).await?;
 
     Ok(())
 }
 
-async fn emergency_help_message(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "prefix (characters): Sets the server's bot prefix \n\n",
-        "resetprefix: Reset's the server's prefix back to the default one"
-    );
+pub fn get_help_reply(locale: &Locale, words: &[String]) -> CommandReply {
+    let groups = MASTER_GROUP.options.sub_groups;
 
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("CourtJester Emergency Help");
-                e.description("You should only use this if you mess up your prefix!");
-                e.field("Commands", content, false);
-                e
-            })
-        })
-        .await;
+    if words.is_empty() {
+        return CommandReply::Embed(help_helper::get_default_help_embed(locale, groups));
+    }
+
+    // Groups take priority so `help starboard` shows the group, subcommands are looked up with more words
+    let group = if words.len() == 1 {
+        help_helper::find_group(groups, &words[0])
+    } else {
+        None
+    };
+
+    match group {
+        Some(group) => CommandReply::Embed(help_helper::get_group_help_embed(locale, group)),
+        None => match help_helper::find_command(groups, words) {
+            Some((command, path)) => {
+                CommandReply::Embed(help_helper::get_command_help_embed(locale, command, &path))
+            }
+            None => {
+                CommandReply::Text(locale.format("help.not_found", &[("name", &words.join(" "))]))
+            }
+        },
+    }
 }
 
-async fn default_help_message(ctx: &Context, channel_id: ChannelId) {
-    let categories = concat!(
-        "prefix \n",
-        "command \n",
-        "starboard \n",
-        "utilities \n",
-        "senders \n",
-        "ciphers \n",
-        "text \n",
-        "voice \n",
-        "music \n",
-        "images \n",
-        "japan \n"
-    );
-
-    let _ = channel_id
+/// Sends the support server and contact information
+#[command]
+async fn support(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let support_embed = embed_store::get_support_embed(&locale);
+
+    msg.channel_id
         .send_message(ctx, |m| {
             m.embed(|e| {
-                e.title("CourtJester Help");
-                e.description(concat!(
-                    "Help for the CourtJester Discord bot \n",
-                    "Command parameters: <> is required and () is optional \n",
-                    "Please use `help <subcategory>` to see that category's help"
-                ));
-                e.field("Subcategories", format!("```\n{}```", categories), false);
-                e.footer(|f| {
-                    f.text("Use the support command for any further help!");
-                    f
-                });
+                e.0 = support_embed.0;
                 e
             })
         })
-        .await;
+        .await?;
+
+    Ok(())
 }
 
+/// Shows information about the bot
 #[command]
-async fn support(ctx: &Context, msg: &Message) -> CommandResult {
+async fn info(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+    let info_embed = get_info_embed(ctx, &locale).await?;
+
     msg.channel_id
         .send_message(ctx, |m| {
             m.embed(|e| {
-                e.title("CourtJester Support");
-                e.description("Need more help?");
-                e.field("Support Server", "https://discord.gg/pswt7by", false);
-                e.field(
-                    "Github repository",
-                    "https://github.com/bdashore3/courtjester",
-                    false,
-                );
-                e.field("kingbri's twitter", "https://twitter.com/kingbri1st", false);
-                e.footer(|f| {
-                    f.text("Created with ❤️ by kingbri#6666");
-                    f
-                })
+                e.0 = info_embed.0;
+                e
             })
         })
         .await?;
@@ -123,46 +104,45 @@ async fn support(ctx: &Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
-#[command]
-async fn info(ctx: &Context, msg: &Message) -> CommandResult {
+pub async fn get_info_embed(ctx: &Context, locale: &Locale) -> CommandResult<CreateEmbed> {
     let mut eb = CreateEmbed::default();
 
     let guild_count = ctx.cache.guilds().await.len();
     let channel_count = ctx.cache.guild_channel_count().await;
     let user_count = ctx.cache.user_count().await;
 
-    let guild_name = if guild_count < 2 { "guild" } else { "guilds" };
+    let guild_name = if guild_count < 2 {
+        locale.get("info.guild")
+    } else {
+        locale.get("info.guilds")
+    };
 
     let last_commit = get_last_commit(ctx).await?;
     let sys_info = get_system_info(ctx).await?;
 
     let mut story_string = String::new();
-    story_string.push_str(&format!(
-        "Currently running on commit [{}]({}) \n",
-        &last_commit.sha[..7],
-        last_commit.html_url
+    story_string.push_str(&locale.format(
+        "info.commit",
+        &[
+            ("sha", &&last_commit.sha[..7]),
+            ("url", &last_commit.html_url),
+        ],
     ));
-    story_string.push_str(&format!("Inside `{}` {} \n", guild_count, guild_name));
-    story_string.push_str(&format!("With `{}` total channels \n", channel_count));
-    story_string.push_str(&format!("Along with `{}` faithful users \n", user_count));
-    story_string.push_str(&format!(
-        "Consuming `{:.3} MB` of memory \n",
-        sys_info.memory
+    story_string.push_str(&locale.format(
+        "info.guild_count",
+        &[("count", &guild_count), ("guilds", &guild_name)],
     ));
-    story_string.push_str(&format!("With a latency of `{}`", sys_info.shard_latency));
+    story_string.push_str(&locale.format("info.channels", &[("count", &channel_count)]));
+    story_string.push_str(&locale.format("info.users", &[("count", &user_count)]));
+    story_string.push_str(&locale.format(
+        "info.memory",
+        &[("memory", &format!("{:.3}", sys_info.memory))],
+    ));
+    story_string.push_str(&locale.format("info.latency", &[("latency", &sys_info.shard_latency)]));
 
-    eb.title("CourtJester is");
+    eb.title(locale.get("info.title"));
     eb.color(0xfda50f);
     eb.description(story_string);
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.0 = eb.0;
-                e
-            })
-        })
-        .await?;
-
-    Ok(())
+    Ok(eb)
 }
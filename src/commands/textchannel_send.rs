@@ -10,8 +10,9 @@ use std::time::Duration;
 use tokio::time::sleep;
 
 use crate::{
-    helpers::{command_utils, permissions_helper},
-    ConnectionPool,
+    helpers::{command_utils, locale_helper, permissions_helper},
+    structures::PermissionLevel,
+    ConnectionPool, StarboardMap,
 };
 
 struct TextChannels {
@@ -20,10 +21,12 @@ struct TextChannels {
     quote_id: Option<i64>,
 }
 
-/// Sends `nice` to a specified channel. Provide a channel as the first argument to set it
-/// Usage: `nice <message>` or `nice <channel>`
+/// Sends nice to the server's nice channel. A moderator can provide a channel to set it
 #[command]
+#[usage = "(channel mention)"]
+#[only_in(guilds)]
 async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let guild_id = msg.guild_id.unwrap();
 
     let test_id = args.single::<String>().unwrap_or_default();
@@ -44,7 +47,8 @@ async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     .await?;
 
     if let Some(channel_id) = parse_channel(&test_id) {
-        if permissions_helper::check_permission(ctx, &msg, None, false).await? {
+        if permissions_helper::check_permission(ctx, &msg, None, PermissionLevel::Moderator).await?
+        {
             if check.exists.unwrap() {
                 sqlx::query!(
                     "UPDATE text_channels SET nice_id = $1 WHERE guild_id = $2",
@@ -57,7 +61,9 @@ async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
                 insert_or_update(&pool, guild_id, "nice", channel_id as i64).await?;
             }
 
-            msg.channel_id.say(ctx, "Channel sucessfully set!").await?;
+            msg.channel_id
+                .say(ctx, locale.get("senders.channel_set"))
+                .await?;
         }
 
         return Ok(());
@@ -65,14 +71,14 @@ async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if !args.is_empty() {
         msg.channel_id
-            .say(ctx, "Please execute this command without any arguments")
+            .say(ctx, locale.get("senders.no_arguments"))
             .await?;
         return Ok(());
     }
 
     if !check.exists.unwrap() {
         msg.channel_id
-            .say(ctx, "The Nice channel isn't set! Please specify a channel!")
+            .say(ctx, locale.get("senders.nice_unset"))
             .await?;
         return Ok(());
     }
@@ -81,7 +87,7 @@ async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if channel_num.nice_id.is_none() {
         msg.channel_id
-            .say(ctx, "The Nice channel isn't set! Please specify a channel!")
+            .say(ctx, locale.get("senders.nice_unset"))
             .await?;
         return Ok(());
     }
@@ -91,8 +97,12 @@ async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
         .send_message(ctx, |m| {
             m.embed(|e| {
                 e.color(0x290e05);
-                e.title(format!("Nice - {}", msg.author.name));
-                e.field("Source", format!("[Jump!]({})", message_url), false)
+                e.title(locale.format("senders.nice_title", &[("user", &msg.author.name)]));
+                e.field(
+                    locale.get("source"),
+                    locale.format("jump", &[("url", &message_url)]),
+                    false,
+                )
             })
         })
         .await?;
@@ -100,10 +110,12 @@ async fn nice(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     Ok(())
 }
 
-/// Sends `bruh` to a specified channel. Provide a channel as the first argument to set it
-/// Usage: `bruh <message>` or `bruh <channel>`
+/// Sends a bruh moment to the server's bruh channel. A moderator can provide a channel to set it
 #[command]
+#[usage = "(channel mention)"]
+#[only_in(guilds)]
 async fn bruh(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let guild_id = msg.guild_id.unwrap();
 
     let test_id = args.single::<String>().unwrap_or_default();
@@ -124,7 +136,7 @@ async fn bruh(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     .await?;
 
     if let Some(channel_id) = parse_channel(&test_id) {
-        if permissions_helper::check_permission(ctx, msg, None, false).await? {
+        if permissions_helper::check_permission(ctx, msg, None, PermissionLevel::Moderator).await? {
             if check.exists.unwrap() {
                 sqlx::query!(
                     "UPDATE text_channels SET bruh_id = $1 WHERE guild_id = $2",
@@ -137,7 +149,9 @@ async fn bruh(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
                 insert_or_update(&pool, guild_id, "bruh", channel_id as i64).await?;
             }
 
-            msg.channel_id.say(ctx, "Channel sucessfully set!").await?;
+            msg.channel_id
+                .say(ctx, locale.get("senders.channel_set"))
+                .await?;
         }
 
         return Ok(());
@@ -145,14 +159,14 @@ async fn bruh(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if !args.is_empty() {
         msg.channel_id
-            .say(ctx, "Please execute this command without any arguments")
+            .say(ctx, locale.get("senders.no_arguments"))
             .await?;
         return Ok(());
     }
 
     if !check.exists.unwrap() {
         msg.channel_id
-            .say(ctx, "The Bruh channel isn't set! Please specify a channel!")
+            .say(ctx, locale.get("senders.bruh_unset"))
             .await?;
         return Ok(());
     }
@@ -161,24 +175,30 @@ async fn bruh(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if channel_nums.bruh_id.is_none() {
         msg.channel_id
-            .say(ctx, "The Bruh channel isn't set! Please specify a channel!")
+            .say(ctx, locale.get("senders.bruh_unset"))
             .await?;
         return Ok(());
     }
 
     let message_url = command_utils::get_message_url(msg.guild_id.unwrap(), msg.channel_id, msg.id);
 
-    msg.channel_id.say(ctx, "***BRUH MOMENT***").await?;
+    msg.channel_id
+        .say(ctx, locale.get("senders.bruh_moment"))
+        .await?;
     ChannelId(channel_nums.bruh_id.unwrap() as u64)
         .send_message(ctx, |m| {
             m.embed(|e| {
                 e.color(0xfc5e03);
-                e.title("Ladies and Gentlemen!");
-                e.description(format!(
-                    "A bruh moment has been declared by {}",
-                    msg.author.mention()
+                e.title(locale.get("senders.bruh_title"));
+                e.description(locale.format(
+                    "senders.bruh_description",
+                    &[("user", &msg.author.mention())],
                 ));
-                e.field("Source", format!("[Jump!]({})", message_url), false)
+                e.field(
+                    locale.get("source"),
+                    locale.format("jump", &[("url", &message_url)]),
+                    false,
+                )
             })
         })
         .await?;
@@ -186,36 +206,28 @@ async fn bruh(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     Ok(())
 }
 
-/// Quotes yourself or a specified user
-/// Usage: `quote <user mention> <content>` or `quote <content>`
+/// Quotes yourself or a mentioned user. Disabled while the server has a starboard
 #[command]
+#[usage = "(user mention) <text>"]
+#[only_in(guilds)]
 async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
     let guild_id = msg.guild_id.unwrap();
     let mut self_quote = true;
 
     let test_id = args.parse::<String>().unwrap_or_default();
 
-    let pool = ctx
-        .data
-        .read()
-        .await
-        .get::<ConnectionPool>()
-        .cloned()
-        .unwrap();
+    let (pool, starboard_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let starboard_map = data.get::<StarboardMap>().cloned().unwrap();
 
-    let starboard_data = sqlx::query!(
-        "SELECT starboard_threshold FROM guild_info WHERE guild_id = $1",
-        guild_id.0 as i64
-    )
-    .fetch_one(&pool)
-    .await?;
+        (pool, starboard_map)
+    };
 
-    if starboard_data.starboard_threshold.is_some() {
+    if starboard_map.contains_key(&guild_id) {
         msg.channel_id
-            .say(
-                ctx,
-                "You can't use the quote command because starboard is enabled in this server!",
-            )
+            .say(ctx, locale.get("senders.quote_starboard"))
             .await?;
         return Ok(());
     }
@@ -228,7 +240,7 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     .await?;
 
     if let Some(channel_id) = parse_channel(&test_id) {
-        if permissions_helper::check_permission(ctx, msg, None, false).await? {
+        if permissions_helper::check_permission(ctx, msg, None, PermissionLevel::Moderator).await? {
             if check.exists.unwrap() {
                 sqlx::query!(
                     "UPDATE text_channels SET quote_id = $1 WHERE guild_id = $2",
@@ -241,7 +253,9 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
                 insert_or_update(&pool, guild_id, "quote", channel_id as i64).await?;
             }
 
-            msg.channel_id.say(ctx, "Channel sucessfully set!").await?;
+            msg.channel_id
+                .say(ctx, locale.get("senders.channel_set"))
+                .await?;
         }
 
         return Ok(());
@@ -249,10 +263,7 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if !check.exists.unwrap() {
         msg.channel_id
-            .say(
-                ctx,
-                "The Quote channel isn't set! Please specify a channel!",
-            )
+            .say(ctx, locale.get("senders.quote_unset"))
             .await?;
         return Ok(());
     }
@@ -263,10 +274,7 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if args.is_empty() || test_id.is_empty() {
         msg.channel_id
-            .say(
-                ctx,
-                "Please provide the quote (with author if you are quoting someone else)",
-            )
+            .say(ctx, locale.get("senders.quote_missing"))
             .await?;
         return Ok(());
     }
@@ -275,10 +283,7 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
 
     if channels.quote_id.is_none() {
         msg.channel_id
-            .say(
-                ctx,
-                "The Quote channel isn't set! Please specify a channel!",
-            )
+            .say(ctx, locale.get("senders.quote_unset"))
             .await?;
         return Ok(());
     }
@@ -322,7 +327,11 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
                     args.advance();
                     e.description(args.rest());
                 }
-                e.field("Source", format!("[Jump!]({})", message_url), false)
+                e.field(
+                    locale.get("source"),
+                    locale.format("jump", &[("url", &message_url)]),
+                    false,
+                )
             })
         })
         .await?;
@@ -330,19 +339,24 @@ async fn quote(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     Ok(())
 }
 
+/// Checks your vibe. Try it out!
 #[command]
 async fn vibecheck(ctx: &Context, msg: &Message) -> CommandResult {
-    msg.channel_id.say(ctx, "Initiating vibe check...").await?;
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    msg.channel_id
+        .say(ctx, locale.get("senders.vibecheck_start"))
+        .await?;
 
     sleep(Duration::from_secs(3)).await;
 
     if random() {
         let success_vec = vec![
-            "Continue vibing good sir/madam",
-            "Have a wonderful day",
-            "Your wish will come true",
-            "STRAIGHT vibing! I like that",
-            "Drop your favorite vibes in the chat",
+            "senders.vibecheck_success_1",
+            "senders.vibecheck_success_2",
+            "senders.vibecheck_success_3",
+            "senders.vibecheck_success_4",
+            "senders.vibecheck_success_5",
         ];
 
         let mut rng = StdRng::from_entropy();
@@ -352,10 +366,12 @@ async fn vibecheck(ctx: &Context, msg: &Message) -> CommandResult {
         msg.channel_id
             .say(
                 ctx,
-                format!(
-                    "{} has passed the vibe check. {}.",
-                    msg.author.mention(),
-                    success_vec[val]
+                locale.format(
+                    "senders.vibecheck_passed",
+                    &[
+                        ("user", &msg.author.mention()),
+                        ("message", &locale.get(success_vec[val])),
+                    ],
                 ),
             )
             .await?;
@@ -363,9 +379,9 @@ async fn vibecheck(ctx: &Context, msg: &Message) -> CommandResult {
         msg.channel_id
             .say(
                 ctx,
-                format!(
-                    "{} has failed the vibe check. Show me your vibing license!",
-                    msg.author.mention()
+                locale.format(
+                    "senders.vibecheck_failed",
+                    &[("user", &msg.author.mention())],
                 ),
             )
             .await?;
@@ -433,27 +449,3 @@ async fn insert_or_update(
 
     Ok(())
 }
-
-pub async fn sender_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "nice: Sends nice to a defined channel \n\n",
-        "bruh: Sends a bruh moment to a defined channel \n\n",
-        "quote <author> <text>: Quotes a user. Deactivated when starboard is enabled \n\n",
-        "vibecheck: Checks your vibe. Try it out!"
-    );
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Textchannel Sender Help");
-                e.description("Description: Commands that send messages to specified channels");
-                e.field("Commands", content, false);
-                e.footer(|f| {
-                    f.text("Adding a channel mention will set the sender channel (Moderator only)");
-                    f
-                });
-                e
-            })
-        })
-        .await;
-}
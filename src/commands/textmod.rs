@@ -6,14 +6,20 @@ use serenity::{
 
 use crate::{helpers::*, JesterError};
 
-/// Outputs a spongebob mock string
-/// Usage: `mock <message>`
+/// Spongebob mocks a message
 #[command]
+#[usage = "<message>"]
+#[example = "I love this bot"]
 #[min_args(1)]
 pub async fn mock(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to mock"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to mock").localize(&locale),
+            )
             .await?;
     } else {
         let mock_string = textmod_helper::get_mock_string(args.rest());
@@ -38,14 +44,19 @@ pub async fn mockl(ctx: &Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
-/// Inverts the characters in a string
-/// Usage: `inv <message>`
+/// Inverts the capitalization of each letter in the message
 #[command]
+#[usage = "<message>"]
 #[min_args(1)]
 async fn inv(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to invert"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to invert").localize(&locale),
+            )
             .await?;
     } else {
         let inv_string = textmod_helper::get_inverted_string(args.rest());
@@ -70,14 +81,19 @@ async fn invl(ctx: &Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
-/// Converts the provided string to uppercase letters
-/// Usage: `upp <message>`
+/// Converts every letter in the message to uppercase
 #[command]
+#[usage = "<message>"]
 #[min_args(1)]
 async fn upp(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to uppercase"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to uppercase").localize(&locale),
+            )
             .await?;
     } else {
         msg.channel_id.say(ctx, args.rest().to_uppercase()).await?;
@@ -100,14 +116,19 @@ async fn uppl(ctx: &Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
-/// Converts the provided string to lowercase
-/// Usage: `low <message>`
+/// Converts every letter in the message to lowercase
 #[command]
+#[usage = "<message>"]
 #[min_args(1)]
 async fn low(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to lowercase"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to lowercase").localize(&locale),
+            )
             .await?;
     } else {
         msg.channel_id.say(ctx, args.rest().to_lowercase()).await?;
@@ -131,13 +152,18 @@ async fn lowl(ctx: &Context, msg: &Message) -> CommandResult {
 }
 
 /// Puts a random amount of spaces between each character of the message
-/// Usage: `space <message>`
 #[command]
+#[usage = "<message>"]
 #[min_args(1)]
 async fn space(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to space out"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to space out").localize(&locale),
+            )
             .await?;
     } else {
         let spaced_string = textmod_helper::get_spaced_string(args.rest(), false);
@@ -162,17 +188,19 @@ async fn spacel(ctx: &Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
-/// Similar to space, but puts a larger amount of space between each character
-/// Usage: `biggspace <message>`
+/// Same as space, but W I D E R
 #[command]
+#[usage = "<message>"]
 #[aliases("bigspace")]
 #[min_args(1)]
 async fn biggspace(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
             .say(
                 ctx,
-                JesterError::MissingError("string to B I G G S P A C E"),
+                JesterError::MissingError("string to B I G G S P A C E").localize(&locale),
             )
             .await?;
     } else {
@@ -198,11 +226,18 @@ async fn biggspacel(ctx: &Context, msg: &Message) -> CommandResult {
     Ok(())
 }
 
+/// Become a hackerman by making h4ck3d w0rd5
 #[command]
+#[usage = "<message>"]
 async fn h4ck(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to h4ck"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to h4ck").localize(&locale),
+            )
             .await?;
     } else {
         let hacked_string = textmod_helper::get_hacked_string(args.rest());
@@ -213,11 +248,18 @@ async fn h4ck(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
     Ok(())
 }
 
+/// Translates the message to the uwu wanguwage uwu
 #[command]
+#[usage = "<message>"]
 async fn uwu(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     if args.is_empty() {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("string to uwu"))
+            .say(
+                ctx,
+                JesterError::MissingError("string to uwu").localize(&locale),
+            )
             .await?;
     } else {
         let uwu_string = textmod_helper::get_uwu_string(args.rest());
@@ -227,34 +269,3 @@ async fn uwu(ctx: &Context, msg: &Message, args: Args) -> CommandResult {
 
     Ok(())
 }
-
-pub async fn textmod_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "mock <message>: Spongebob mocks a string \n\n",
-        "inv <message>: Inverts capitalization of each letter in the message \n\n",
-        "upp <message>: Every letter becomes uppercase \n\n",
-        "low <message>: Every letter becomes lowercase \n\n",
-        "space <message>: Spaces out each letter in the message (whitespace omitted) \n\n",
-        "biggspace <message>: Same as space, but W I D E R \n\n",
-        "h4ck <message>: Become a hackerman by making h4ck3d w0rd5 \n\n",
-        "uwu <message>: Translate to the uwu wanguwage uwu"
-    );
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Text Modification Help");
-                e.description("Description: Commands that modify text");
-                e.field("Commands", content, false);
-                e.footer(|f| {
-                    f.text(concat!(
-                        "Putting an l in front of any command",
-                        "(except h4ck and uwu) will use the last message"
-                    ));
-                    f
-                });
-                e
-            })
-        })
-        .await;
-}
@@ -1,22 +1,34 @@
 use std::borrow::Cow;
 
 use serenity::{
+    builder::CreateEmbed,
     framework::standard::{macros::command, Args, CommandResult},
     http::AttachmentType,
     model::prelude::*,
     prelude::*,
 };
 
-use crate::JesterError;
+use crate::{
+    helpers::{command_utils, locale_helper},
+    structures::{CommandReply, Locale},
+    JesterError,
+};
 
+/// Gets your own, or the mentioned person's avatar
 #[command]
+#[usage = "(user mention/ID)"]
 async fn avatar(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let user = if let Ok(user_id) = args.single::<UserId>() {
         match user_id.to_user(ctx).await {
             Ok(user) => Cow::Owned(user),
             Err(_) => {
                 msg.channel_id
-                    .say(ctx, JesterError::MissingError("User ID/mention"))
+                    .say(
+                        ctx,
+                        JesterError::MissingError("User ID/mention").localize(&locale),
+                    )
                     .await?;
 
                 return Ok(());
@@ -26,7 +38,10 @@ async fn avatar(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
         Cow::Borrowed(&msg.author)
     } else {
         msg.channel_id
-            .say(ctx, JesterError::MissingError("User ID/mention"))
+            .say(
+                ctx,
+                JesterError::MissingError("User ID/mention").localize(&locale),
+            )
             .await?;
 
         return Ok(());
@@ -37,15 +52,23 @@ async fn avatar(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     Ok(())
 }
 
+/// Steals an emoji from anywhere and adds it to the server
 #[command]
+#[usage = "<emoji> (new name)"]
 #[aliases("steal")]
 #[required_permissions("MANAGE_EMOJIS")]
+#[only_in(guilds)]
 async fn kang(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let emoji = match args.single::<EmojiIdentifier>() {
         Ok(id) => id,
         Err(_) => {
             msg.channel_id
-                .say(ctx, JesterError::MissingError("custom emoji"))
+                .say(
+                    ctx,
+                    JesterError::MissingError("custom emoji").localize(&locale),
+                )
                 .await?;
 
             return Ok(());
@@ -55,7 +78,7 @@ async fn kang(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let guild = msg.guild(ctx).await.unwrap();
     if guild.emojis.contains_key(&emoji.id) {
         msg.channel_id
-            .say(ctx, "This emoji already exists in this server! Aborting...")
+            .say(ctx, locale.get("utility.emoji_exists"))
             .await?;
 
         return Ok(());
@@ -77,70 +100,84 @@ async fn kang(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
             msg.channel_id
                 .say(
                     ctx,
-                    format!("New emoji {} created! {}", new_emoji.name, new_emoji),
+                    locale.format(
+                        "utility.emoji_created",
+                        &[("name", &new_emoji.name), ("emoji", &new_emoji)],
+                    ),
                 )
                 .await?;
 
             Ok(())
         }
         Err(e) => {
-            msg.channel_id.say(
-                ctx,
-                "Something went wrong with emoji creation. Check your emoji limit? The error message is below."
-            ).await?;
+            msg.channel_id
+                .say(ctx, locale.get("utility.emoji_failed"))
+                .await?;
 
             Err(e.into())
         }
     }
 }
 
+/// Gets the information of an emoji
 #[command]
+#[usage = "<emoji>"]
 #[aliases("einfo")]
 pub async fn emoji_info(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
-    let emoji = match args.single::<EmojiIdentifier>() {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    let input = args.single::<String>().unwrap_or_default();
+    let reply = get_emoji_info_reply(&locale, &input, &msg.author);
+
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+// Embed with emoji name, image as thumbnail, and original link to image
+pub fn get_emoji_info_reply(locale: &Locale, input: &str, requester: &User) -> CommandReply {
+    let emoji = match input.parse::<EmojiIdentifier>() {
         Ok(id) => id,
         Err(_) => {
-            msg.channel_id
-                .say(ctx, JesterError::MissingError("custom emoji"))
-                .await?;
-
-            return Ok(());
+            return CommandReply::Text(JesterError::MissingError("custom emoji").localize(locale))
         }
     };
 
     let emoji_url = emoji.url();
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Emoji info for...");
-                e.thumbnail(&emoji_url);
-                e.field("Name", emoji.name, false);
-                e.field("Emoji ID", emoji.id.0, false);
-                e.field("Image URL", format!("[Click here]({})", &emoji_url), false);
-                e.footer(|f| {
-                    f.text(format!(
-                        "Requested by {}#{}",
-                        msg.author.name, msg.author.discriminator
-                    ));
-                    f
-                });
-                e
-            })
-        })
-        .await?;
+    let mut eb = CreateEmbed::default();
+    eb.title(locale.get("utility.emoji_info"));
+    eb.thumbnail(&emoji_url);
+    eb.field(locale.get("utility.emoji_name"), emoji.name, false);
+    eb.field(locale.get("utility.emoji_id"), emoji.id.0, false);
+    eb.field(
+        locale.get("utility.emoji_url"),
+        locale.format("utility.click_here", &[("url", &emoji_url)]),
+        false,
+    );
+    eb.footer(|f| {
+        f.text(locale.format("utility.requested_by", &[("user", &requester.tag())]));
+        f
+    });
 
-    Ok(())
-    // Embed with emoji name, image as thumbnail, and original link to image
+    CommandReply::Embed(eb)
 }
 
+/// Creates a spoiler from an attached file
 #[command]
+#[usage = "<attachment>"]
+#[only_in(guilds)]
 async fn spoiler(ctx: &Context, msg: &Message) -> CommandResult {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     let attachment = match msg.attachments.get(0) {
         Some(attachment) => attachment,
         None => {
             msg.channel_id
-                .say(ctx, JesterError::MissingError("attachment"))
+                .say(
+                    ctx,
+                    JesterError::MissingError("attachment").localize(&locale),
+                )
                 .await?;
 
             return Ok(());
@@ -159,7 +196,7 @@ async fn spoiler(ctx: &Context, msg: &Message) -> CommandResult {
     let msg_result = msg
         .channel_id
         .send_message(ctx, |m| {
-            m.content(format!("Invoked by {}", msg.author.mention()));
+            m.content(locale.format("utility.invoked_by", &[("user", &msg.author.mention())]));
             m.add_file(new_attachment);
             m
         })
@@ -167,44 +204,19 @@ async fn spoiler(ctx: &Context, msg: &Message) -> CommandResult {
 
     if msg_result.is_err() {
         msg.channel_id
-            .say(
-                ctx,
-                "This file is too big! Please attach a file less than 8 MB...",
-            )
+            .say(ctx, locale.get("utility.file_too_big"))
             .await?;
 
         return Ok(());
     }
 
     if msg.delete(ctx).await.is_err() {
-        msg.channel_id.say(
-            ctx,
-            concat!("The spoiled attachment was posted, but I cannot delete the old message! \n",
-            "Please give me the `MANAGE_MESSAGES` permission if you want the unspoiled image deleted!")
-        ).await?;
+        msg.channel_id
+            .say(ctx, locale.get("utility.spoiler_not_deleted"))
+            .await?;
 
         return Ok(());
     };
 
     Ok(())
 }
-
-pub async fn utility_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "avatar (user mention/ID): Gets your own, or the mentioned person's avatar \n\n",
-        "spoiler <attachment>: Creates a spoiler from an attached file \n\n",
-        "kang <emoji> (new name): Steal an emoji from anywhere and load it to your server. Requires the `manage emojis` permission \n\n",
-        "einfo <emoji>: Get the information of an emoji"
-    );
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Miscellaneous Utility Help");
-                e.description("Description: Various utility commands");
-                e.field("Commands", content, false);
-                e
-            })
-        })
-        .await;
-}
@@ -1,14 +1,23 @@
 use std::sync::atomic::{AtomicBool, Ordering};
 
-use crate::{helpers::start_loops, reactions::reaction_handler, ConnectionPool, PrefixMap};
+use crate::{
+    helpers::{autoresponse_helper, start_loops},
+    interactions::{interaction_handler, registry},
+    reactions::reaction_handler,
+    structures::BlockTarget,
+    AliasMap, AutoResponderMap, Blocklist, CommandRuleMap, ConnectionPool, CooldownMap,
+    CooldownTracker, CustomCommandMap, GuildLanguageMap, MentionCommandMap, PermissionRoleMap,
+    PrefixMap, StarboardFilterMap, StarboardMap, SuggestionOptOuts, UsageBuffer,
+};
 use lavalink_rs::gateway::LavalinkEventHandler;
 use serenity::{
     async_trait,
     client::{Context, EventHandler},
     model::{
-        channel::Reaction,
-        guild::{Guild, GuildUnavailable},
-        id::GuildId,
+        channel::{Message, Reaction},
+        guild::{Guild, GuildUnavailable, Role},
+        id::{GuildId, RoleId},
+        interactions::Interaction,
         prelude::Ready,
     },
 };
@@ -32,13 +41,16 @@ impl EventHandler for SerenityHandler {
                 panic!("Error when pruning guilds! {}", e);
             }
 
-            let pool = ctx
-                .data
-                .read()
-                .await
-                .get::<ConnectionPool>()
-                .cloned()
-                .unwrap();
+            let (pool, usage_buffer, cooldown_tracker) = {
+                let data = ctx.data.read().await;
+                let pool = data.get::<ConnectionPool>().cloned().unwrap();
+                let usage_buffer = data.get::<UsageBuffer>().cloned().unwrap();
+                let cooldown_tracker = data.get::<CooldownTracker>().cloned().unwrap();
+
+                (pool, usage_buffer, cooldown_tracker)
+            };
+
+            let usage_pool = pool.clone();
 
             println!("Starting starboard deletion loop!");
             tokio::spawn(async move {
@@ -47,6 +59,21 @@ impl EventHandler for SerenityHandler {
                 };
             });
 
+            println!("Starting command usage loop!");
+            tokio::spawn(async move {
+                start_loops::usage_flush_loop(&usage_pool, usage_buffer).await;
+            });
+
+            println!("Starting cooldown pruning loop!");
+            tokio::spawn(async move {
+                start_loops::cooldown_prune_loop(cooldown_tracker).await;
+            });
+
+            println!("Registering slash commands!");
+            if let Err(e) = registry::register_slash_commands(&ctx).await {
+                eprintln!("Error when registering slash commands! {}", e);
+            }
+
             println!("Starting activity loop!");
             tokio::spawn(async move {
                 start_loops::activity_loop(&ctx.shard).await;
@@ -55,17 +82,29 @@ impl EventHandler for SerenityHandler {
     }
 
     async fn guild_create(&self, ctx: Context, guild: Guild, is_new: bool) {
-        let pool = ctx
-            .data
-            .read()
-            .await
-            .get::<ConnectionPool>()
-            .cloned()
-            .unwrap();
+        let (pool, blocklist) = {
+            let data = ctx.data.read().await;
+            let pool = data.get::<ConnectionPool>().cloned().unwrap();
+            let blocklist = data.get::<Blocklist>().cloned().unwrap();
+
+            (pool, blocklist)
+        };
+
+        // Leaving fires guild_delete, which cleans up anything stored for the guild
+        if blocklist.contains(&BlockTarget::Guild(guild.id)) {
+            if let Err(e) = guild.id.leave(&ctx).await {
+                eprintln!(
+                    "Error when leaving a blocked guild! (ID {}): {}",
+                    guild.id.0, e
+                )
+            }
+
+            return;
+        }
 
         if is_new {
             sqlx::query!(
-                "INSERT INTO guild_info VALUES($1, null) ON CONFLICT DO NOTHING",
+                "INSERT INTO guild_info(guild_id) VALUES($1) ON CONFLICT DO NOTHING",
                 guild.id.0 as i64
             )
             .execute(&pool)
@@ -75,12 +114,51 @@ impl EventHandler for SerenityHandler {
     }
 
     async fn guild_delete(&self, ctx: Context, incomplete: GuildUnavailable, _full: Option<Guild>) {
-        let (pool, prefixes) = {
+        let (
+            pool,
+            prefixes,
+            command_rules,
+            cooldowns,
+            languages,
+            aliases,
+            permission_roles,
+            mention_commands,
+            autoresponders,
+            custom_commands,
+            suggestion_opt_outs,
+            starboards,
+            starboard_filters,
+        ) = {
             let data = ctx.data.read().await;
             let pool = data.get::<ConnectionPool>().cloned().unwrap();
             let prefixes = data.get::<PrefixMap>().cloned().unwrap();
-
-            (pool, prefixes)
+            let command_rules = data.get::<CommandRuleMap>().cloned().unwrap();
+            let cooldowns = data.get::<CooldownMap>().cloned().unwrap();
+            let languages = data.get::<GuildLanguageMap>().cloned().unwrap();
+            let aliases = data.get::<AliasMap>().cloned().unwrap();
+            let permission_roles = data.get::<PermissionRoleMap>().cloned().unwrap();
+            let mention_commands = data.get::<MentionCommandMap>().cloned().unwrap();
+            let autoresponders = data.get::<AutoResponderMap>().cloned().unwrap();
+            let custom_commands = data.get::<CustomCommandMap>().cloned().unwrap();
+            let suggestion_opt_outs = data.get::<SuggestionOptOuts>().cloned().unwrap();
+            let starboards = data.get::<StarboardMap>().cloned().unwrap();
+            let starboard_filters = data.get::<StarboardFilterMap>().cloned().unwrap();
+
+            (
+                pool,
+                prefixes,
+                command_rules,
+                cooldowns,
+                languages,
+                aliases,
+                permission_roles,
+                mention_commands,
+                autoresponders,
+                custom_commands,
+                suggestion_opt_outs,
+                starboards,
+                starboard_filters,
+            )
         };
 
         if let Err(e) = sqlx::query!(
@@ -96,6 +174,58 @@ impl EventHandler for SerenityHandler {
         if prefixes.contains_key(&incomplete.id) {
             prefixes.remove(&incomplete.id);
         }
+
+        command_rules.remove(&incomplete.id);
+        cooldowns.remove(&incomplete.id);
+        languages.remove(&incomplete.id);
+        aliases.remove(&incomplete.id);
+        permission_roles.remove(&incomplete.id);
+        mention_commands.remove(&incomplete.id);
+        autoresponders.remove(&incomplete.id);
+        custom_commands.remove(&incomplete.id);
+        suggestion_opt_outs.remove(&incomplete.id);
+        starboards.remove(&incomplete.id);
+        starboard_filters.remove(&incomplete.id);
+    }
+
+    // Deleted roles can't grant permission levels anymore
+    async fn guild_role_delete(
+        &self,
+        ctx: Context,
+        guild_id: GuildId,
+        role_id: RoleId,
+        _role: Option<Role>,
+    ) {
+        let (pool, permission_roles) = {
+            let data = ctx.data.read().await;
+            let pool = data.get::<ConnectionPool>().cloned().unwrap();
+            let permission_roles = data.get::<PermissionRoleMap>().cloned().unwrap();
+
+            (pool, permission_roles)
+        };
+
+        if let Some(mut levels) = permission_roles.get_mut(&guild_id) {
+            for roles in levels.values_mut() {
+                roles.retain(|x| *x != role_id);
+            }
+        }
+
+        if let Err(e) = sqlx::query!(
+            "DELETE FROM permission_roles WHERE guild_id = $1 AND role_id = $2",
+            guild_id.0 as i64,
+            role_id.0 as i64
+        )
+        .execute(&pool)
+        .await
+        {
+            eprintln!("Error in role removal! (ID {}): {}", role_id.0, e)
+        }
+    }
+
+    async fn message(&self, ctx: Context, msg: Message) {
+        if let Err(e) = autoresponse_helper::handle_message(&ctx, &msg).await {
+            eprintln!("Error when running an auto-responder! {}", e);
+        }
     }
 
     async fn reaction_add(&self, ctx: Context, reaction: Reaction) {
@@ -105,6 +235,12 @@ impl EventHandler for SerenityHandler {
     async fn reaction_remove(&self, ctx: Context, reaction: Reaction) {
         let _ = reaction_handler::dispatch_reaction(&ctx, &reaction, true).await;
     }
+
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        if let Err(e) = interaction_handler::dispatch_interaction(&ctx, &interaction).await {
+            eprintln!("Error when handling interaction! {}", e);
+        }
+    }
 }
 
 pub struct LavalinkHandler;
@@ -1,19 +1,22 @@
 use std::collections::HashSet;
 
 use crate::{
-    helpers::command_utils,
+    helpers::{
+        alias_helper, blocklist_helper, command_utils, cooldown_helper, custom_command_helper,
+        embed_store, error_helper, locale_helper, rules_helper, stats_helper, suggestion_helper,
+    },
     structures::{commands::*, errors::*},
-    ConnectionPool, EmergencyCommands, PrefixMap, PubCreds,
+    EmergencyCommands,
 };
 use serenity::{
     client::Context,
-    framework::standard::{macros::hook, CommandError, DispatchError, StandardFramework},
+    framework::standard::{macros::hook, CommandError, DispatchError, Reason, StandardFramework},
     model::{channel::Message, id::UserId, Permissions},
     prelude::Mentionable,
 };
 
-pub fn get_framework(bot_id: UserId, owners: HashSet<UserId>) -> StandardFramework {
-    StandardFramework::new()
+pub async fn get_framework(bot_id: UserId, owners: HashSet<UserId>) -> StandardFramework {
+    let mut framework = StandardFramework::new()
         .configure(|c| {
             c.dynamic_prefix(dynamic_prefix)
                 .prefix("")
@@ -36,42 +39,73 @@ pub fn get_framework(bot_id: UserId, owners: HashSet<UserId>) -> StandardFramewo
         .group(&MUSIC_GROUP)
         .group(&IMAGES_GROUP)
         .group(&JAPAN_GROUP)
-        .group(&UTILITY_GROUP)
+        .group(&UTILITY_GROUP);
+
+    // Default cooldowns. Guilds can override these with `config cooldown`
+    for bucket in &cooldown_helper::DEFAULT_BUCKETS {
+        framework = framework
+            .bucket(bucket.name, |b| {
+                b.delay(bucket.delay);
+
+                if bucket.limit > 0 {
+                    b.time_span(bucket.time_span).limit(bucket.limit);
+                }
+
+                b.check(no_cooldown_override)
+            })
+            .await;
+    }
+
+    framework
 }
 
 #[hook]
 async fn unrecognized_command_hook(ctx: &Context, msg: &Message, command_name: &str) {
-    let pool = ctx
-        .data
-        .read()
-        .await
-        .get::<ConnectionPool>()
-        .cloned()
-        .unwrap();
-
-    let guild_id = msg.guild_id.unwrap().0 as i64;
-
-    let cmd_data = sqlx::query!(
-        "SELECT content FROM commands WHERE guild_id = $1 AND name = $2",
-        guild_id,
-        command_name
-    )
-    .fetch_optional(&pool)
-    .await
-    .unwrap();
-
-    if let Some(cmd_data) = cmd_data {
-        let content = cmd_data
-            .content
-            .unwrap()
-            .replace("{user}", &msg.author.mention().to_string());
-        let _ = msg.channel_id.say(ctx, content).await;
+    if blocklist_helper::is_blocked(ctx, msg.author.id, msg.guild_id).await {
+        return;
+    }
+
+    // Custom commands and aliases belong to a guild
+    if msg.guild_id.is_none() {
+        return;
+    }
+
+    match custom_command_helper::run_custom_command(ctx, msg, command_name).await {
+        Ok(true) => return,
+        Ok(false) => {}
+        Err(e) => {
+            eprintln!("Error when running a custom command! {}", e);
+            return;
+        }
+    }
+
+    if alias_helper::dispatch_alias(ctx, msg, command_name).await {
+        return;
+    }
+
+    if let Err(e) = suggestion_helper::send_suggestion(ctx, msg, command_name).await {
+        eprintln!("Error when sending a command suggestion! {}", e);
     }
 }
 
 #[hook]
 async fn before(ctx: &Context, msg: &Message, cmd_name: &str) -> bool {
+    // Blocked users don't get any reply, so they can't tell if the bot is ignoring them
+    if blocklist_helper::is_blocked(ctx, msg.author.id, msg.guild_id).await {
+        return false;
+    }
+
+    /*
+     * Emergency commands skip rules and cooldowns so a server can't lock itself out
+     * Commands the guild allowed through mentions are checked like any other command
+     */
     if command_utils::check_mention_prefix(msg) {
+        // Subcommands are allowed through their parent, ex. `prefix add`
+        let command = match command_utils::get_command_location(ctx, msg).await {
+            Some(location) => location.command,
+            None => cmd_name.to_owned(),
+        };
+
         let emergency_commands = ctx
             .data
             .read()
@@ -80,94 +114,142 @@ async fn before(ctx: &Context, msg: &Message, cmd_name: &str) -> bool {
             .cloned()
             .unwrap();
 
-        if emergency_commands.contains(&cmd_name.to_owned()) {
+        if emergency_commands.contains(&command) {
+            let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
             let _ = msg
                 .channel_id
                 .say(
                     ctx,
-                    format!(
-                        "{}, you are running an emergency command!",
-                        msg.author.mention()
-                    ),
+                    locale.format("framework.emergency", &[("user", &msg.author.mention())]),
                 )
                 .await;
+
+            stats_helper::start_timer(ctx, msg).await;
             return true;
-        } else {
+        }
+
+        if !command_utils::get_mention_commands(ctx, msg.guild_id)
+            .await
+            .contains(&command)
+        {
             return false;
         }
     }
 
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+    if !rules_helper::check_command_rules(ctx, msg).await {
+        let _ = msg
+            .channel_id
+            .say(ctx, locale.get("framework.disabled"))
+            .await;
+
+        return false;
+    }
+
+    if let Some(seconds) = cooldown_helper::check_cooldown(ctx, msg).await {
+        let _ = msg
+            .channel_id
+            .say(
+                ctx,
+                locale.format("framework.cooldown", &[("seconds", &seconds)]),
+            )
+            .await;
+
+        return false;
+    }
+
+    stats_helper::start_timer(ctx, msg).await;
+
     true
 }
 
+// Buckets only apply when the guild hasn't overridden the command's cooldown
+#[hook]
+async fn no_cooldown_override(ctx: &Context, msg: &Message) -> bool {
+    !cooldown_helper::has_cooldown_override(ctx, msg).await
+}
+
 // After a command is executed, goto here
 #[hook]
 async fn after(ctx: &Context, msg: &Message, cmd_name: &str, error: Result<(), CommandError>) {
+    stats_helper::record_usage(ctx, msg, cmd_name, error.is_ok()).await;
+
     if let Err(why) = error {
-        let part_1 = "Looks like the bot encountered an error! \n";
-        let part_2 = "Please use the `support` command and send the output to the support server!";
-        let error_string = format!("{}{}", part_1, part_2);
+        let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
 
-        let _ = msg
-            .channel_id
-            .send_message(ctx, |m| {
-                m.embed(|e| {
-                    e.color(0xff69b4);
-                    e.title("Aw Snap!");
-                    e.description(error_string);
-                    e.field("Command Name", cmd_name, false);
-                    e.field("Error", format!("```{} \n```", why), false);
-                    e
-                })
-            })
-            .await;
+        let error_id = match error_helper::log_command_error(ctx, msg, cmd_name, &why).await {
+            Ok(error_id) => error_id.to_string(),
+            Err(e) => {
+                eprintln!("Error when logging a command error! {}: {}", e, why);
+
+                locale.get("framework.error_id_unavailable")
+            }
+        };
+
+        let eb = embed_store::get_error_embed(cmd_name, &error_id, &locale);
+
+        let _ = msg.channel_id.send_message(ctx, |m| m.set_embed(eb)).await;
     }
 }
 
 // On a dispatch error, go to this function
 #[hook]
 async fn dispatch_error(ctx: &Context, msg: &Message, error: DispatchError) {
+    let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
     match error {
-        DispatchError::LackingPermissions(Permissions::ADMINISTRATOR) => {
-            let _ = msg
-                .channel_id
-                .say(
-                    ctx,
-                    JesterError::PermissionError(PermissionType::UserPerm("administrator")),
-                )
-                .await;
+        // Permission level checks already localize their reason
+        DispatchError::CheckFailed(_, Reason::User(reason)) => {
+            let _ = msg.channel_id.say(ctx, reason).await;
         }
-        DispatchError::LackingPermissions(Permissions::MANAGE_MESSAGES) => {
+        DispatchError::CheckFailed(name, Reason::Log(reason)) => {
+            eprintln!("Error in the {} check! {}", name, reason);
+        }
+        DispatchError::LackingPermissions(Permissions::MANAGE_EMOJIS) => {
             let _ = msg
                 .channel_id
                 .say(
                     ctx,
-                    JesterError::PermissionError(PermissionType::UserPerm("manage messages")),
+                    JesterError::PermissionError(PermissionType::UserPerm("manage emojis"))
+                        .localize(&locale),
                 )
                 .await;
         }
-        DispatchError::LackingPermissions(Permissions::MANAGE_EMOJIS) => {
+        DispatchError::NotEnoughArguments { min, given } => {
             let _ = msg
                 .channel_id
                 .say(
                     ctx,
-                    JesterError::PermissionError(PermissionType::UserPerm("manage emojis")),
+                    locale.format(
+                        "framework.not_enough_args",
+                        &[("min", &min), ("given", &given)],
+                    ),
                 )
                 .await;
         }
-        DispatchError::NotEnoughArguments { min, given } => {
+        DispatchError::Ratelimited(info) => {
+            if info.is_first_try {
+                let _ = msg
+                    .channel_id
+                    .say(
+                        ctx,
+                        locale.format("framework.cooldown", &[("seconds", &info.as_secs())]),
+                    )
+                    .await;
+            }
+        }
+        DispatchError::OnlyForGuilds => {
             let _ = msg
                 .channel_id
-                .say(
-                    ctx,
-                    format!("Args required: {}. Args given: {}", min, given),
-                )
+                .say(ctx, locale.get("framework.guild_only"))
                 .await;
         }
         DispatchError::OnlyForOwners => {
             let _ = msg
                 .channel_id
-                .say(ctx, "This is a bot dev only command!")
+                .say(ctx, locale.get("framework.owners_only"))
                 .await;
         }
         _ => println!("Unhandled dispatch error: {:?}", error),
@@ -176,31 +258,16 @@ async fn dispatch_error(ctx: &Context, msg: &Message, error: DispatchError) {
 
 /*
  * The heart of custom prefixes
- * If the guild has a prefix in the Dashmap, use that prefix
- * Otherwise, use the default prefix from credentials_helper
+ * If the guild has prefixes in the Dashmap, use the one the message starts with
+ * Otherwise (or in DMs), use the default prefix from credentials_helper
  */
 
 #[hook]
 async fn dynamic_prefix(ctx: &Context, msg: &Message) -> Option<String> {
-    let (prefixes, default_prefix) = {
-        let data = ctx.data.read().await;
-        let prefixes = data.get::<PrefixMap>().cloned().unwrap();
-        let default_prefix = data
-            .get::<PubCreds>()
-            .unwrap()
-            .get("default prefix")
-            .cloned()
-            .unwrap();
-
-        (prefixes, default_prefix)
-    };
-
-    let guild_id = msg.guild_id.unwrap();
-
-    let wrapped_prefix = prefixes.get(&guild_id);
+    let prefixes = command_utils::get_prefixes(ctx, msg.guild_id).await;
 
-    match wrapped_prefix {
-        Some(prefix_guard) => Some(prefix_guard.value().to_owned()),
-        None => Some(default_prefix),
+    match command_utils::get_matching_prefix(&prefixes, &msg.content) {
+        Some(prefix) => Some(prefix.to_owned()),
+        None => prefixes.into_iter().next(),
     }
 }
@@ -0,0 +1,52 @@
+use serenity::{client::Context, framework::Framework, model::channel::Message};
+
+use crate::{helpers::command_utils, AliasMap, FrameworkContainer};
+
+pub async fn get_alias_target(ctx: &Context, msg: &Message, alias: &str) -> Option<String> {
+    let guild_id = msg.guild_id?;
+    let aliases = ctx.data.read().await.get::<AliasMap>().cloned().unwrap();
+
+    let guild_aliases = aliases.get(&guild_id)?;
+    let target = guild_aliases.get(alias)?;
+
+    Some(target.to_owned())
+}
+
+/*
+ * Rewrites the message so the alias is replaced with the command it points to
+ * The message goes through the framework again, so permissions, rules, and cooldowns still apply
+ * Returns false if the invoked name isn't an alias in the guild
+ */
+pub async fn dispatch_alias(ctx: &Context, msg: &Message, alias: &str) -> bool {
+    let target = match get_alias_target(ctx, msg, alias).await {
+        Some(target) => target,
+        None => return false,
+    };
+
+    let invocation = match command_utils::get_invocation(ctx, msg).await {
+        Some(invocation) => invocation.trim_start(),
+        None => return false,
+    };
+
+    let prefix_end = msg.content.len() - invocation.len();
+
+    let rest = match invocation.strip_prefix(alias) {
+        Some(rest) => rest,
+        None => return false,
+    };
+
+    let framework = ctx
+        .data
+        .read()
+        .await
+        .get::<FrameworkContainer>()
+        .cloned()
+        .unwrap();
+
+    let mut aliased_msg = msg.clone();
+    aliased_msg.content = format!("{}{}{}", &msg.content[..prefix_end], target, rest);
+
+    framework.dispatch(ctx.clone(), aliased_msg).await;
+
+    true
+}
@@ -0,0 +1,84 @@
+use std::convert::TryFrom;
+
+use serenity::{
+    client::Context,
+    framework::standard::CommandResult,
+    model::channel::{Message, ReactionType},
+};
+
+use crate::{
+    helpers::{blocklist_helper, command_utils, cooldown_helper},
+    AutoResponderMap,
+};
+
+/*
+ * Replies or reacts to the first of a guild's auto-responders that matches the message
+ * Commands, bots, and blocked users never trigger them
+ */
+pub async fn handle_message(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    if msg.author.bot || blocklist_helper::is_blocked(ctx, msg.author.id, Some(guild_id)).await {
+        return Ok(());
+    }
+
+    let autoresponder_map = ctx
+        .data
+        .read()
+        .await
+        .get::<AutoResponderMap>()
+        .cloned()
+        .unwrap();
+
+    let autoresponder = match autoresponder_map.get(&guild_id) {
+        Some(autoresponders) => autoresponders
+            .iter()
+            .find(|autoresponder| autoresponder.is_match(msg.channel_id, &msg.content))
+            .cloned(),
+        None => return Ok(()),
+    };
+
+    let autoresponder = match autoresponder {
+        Some(autoresponder) => autoresponder,
+        None => return Ok(()),
+    };
+
+    if command_utils::get_invocation(ctx, msg).await.is_some() {
+        return Ok(());
+    }
+
+    // Cooldowns are per channel, so a busy channel doesn't get the same reply over and over
+    if let Some(seconds) = autoresponder.cooldown {
+        let on_cooldown = cooldown_helper::check_autoresponse_cooldown(
+            ctx,
+            guild_id,
+            msg.channel_id,
+            autoresponder.id,
+            seconds,
+        )
+        .await
+        .is_some();
+
+        if on_cooldown {
+            return Ok(());
+        }
+    }
+
+    if let Some(reaction) = autoresponder.reaction {
+        msg.react(ctx, ReactionType::try_from(reaction)?).await?;
+    }
+
+    if let Some(response) = autoresponder.response {
+        msg.channel_id
+            .send_message(ctx, |m| {
+                m.content(response);
+                m.allowed_mentions(|am| am.empty_parse())
+            })
+            .await?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,16 @@
+use serenity::{
+    client::Context,
+    model::id::{GuildId, UserId},
+};
+
+use crate::{structures::BlockTarget, Blocklist};
+
+// Blocked guilds are left on join, but their messages are still dropped in case the bot is there
+pub async fn is_blocked(ctx: &Context, user_id: UserId, guild_id: Option<GuildId>) -> bool {
+    let blocklist = ctx.data.read().await.get::<Blocklist>().cloned().unwrap();
+
+    blocklist.contains(&BlockTarget::User(user_id))
+        || guild_id.map_or(false, |guild_id| {
+            blocklist.contains(&BlockTarget::Guild(guild_id))
+        })
+}
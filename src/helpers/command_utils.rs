@@ -1,11 +1,21 @@
+use once_cell::sync::Lazy;
 use regex::Regex;
 use serenity::{
-    framework::standard::CommandResult,
+    client::Context,
+    framework::standard::{CommandGroup, CommandResult},
     model::{
         channel::Message,
         id::{ChannelId, GuildId, MessageId},
     },
 };
+use std::collections::HashMap;
+
+use crate::{
+    structures::{CommandLocation, CommandReply},
+    CommandGroupMap, EmergencyCommands, MentionCommandMap, PrefixMap, PubCreds,
+};
+
+static MENTION_PREFIX: Lazy<Regex> = Lazy::new(|| Regex::new(r"^\s*<@!?\d+>").unwrap());
 
 pub fn get_message_url(guild_id: GuildId, channel_id: ChannelId, message_id: MessageId) -> String {
     format!(
@@ -36,13 +46,17 @@ pub fn deconstruct_time(input: String) -> CommandResult<u64> {
 }
 
 pub fn check_mention_prefix(msg: &Message) -> bool {
-    let words = msg.content.split_whitespace().collect::<Vec<&str>>();
+    MENTION_PREFIX.is_match(&msg.content)
+}
 
-    let re = Regex::new(r"<@!?\d+>").unwrap();
+// Returns the text after a leading mention, or None if the message doesn't start with one
+pub fn strip_mention_prefix(content: &str) -> Option<&str> {
+    let mention = MENTION_PREFIX.find(content)?;
 
-    re.is_match(words[0])
+    Some(content[mention.end()..].trim_start())
 }
 
+// These can always be run by mentioning the bot, so a broken prefix can be fixed
 pub fn get_allowed_commands() -> Vec<String> {
     let allowed_commands: Vec<String> = vec![
         "prefix".to_owned(),
@@ -53,3 +67,154 @@ pub fn get_allowed_commands() -> Vec<String> {
 
     allowed_commands
 }
+
+pub async fn get_default_prefix(ctx: &Context) -> String {
+    ctx.data
+        .read()
+        .await
+        .get::<PubCreds>()
+        .unwrap()
+        .get("default prefix")
+        .cloned()
+        .unwrap()
+}
+
+pub async fn get_guild_prefixes(ctx: &Context, guild_id: GuildId) -> Vec<String> {
+    let prefixes = ctx.data.read().await.get::<PrefixMap>().cloned().unwrap();
+
+    match prefixes.get(&guild_id) {
+        Some(prefix_guard) => prefix_guard.value().to_owned(),
+        None => vec![get_default_prefix(ctx).await],
+    }
+}
+
+// DMs don't have custom prefixes, so only the default prefix is used there
+pub async fn get_prefixes(ctx: &Context, guild_id: Option<GuildId>) -> Vec<String> {
+    match guild_id {
+        Some(guild_id) => get_guild_prefixes(ctx, guild_id).await,
+        None => vec![get_default_prefix(ctx).await],
+    }
+}
+
+/*
+ * Commands that can be run by mentioning the bot
+ * Guilds can add their own with `config mention`, but the emergency commands are always allowed
+ */
+pub async fn get_mention_commands(ctx: &Context, guild_id: Option<GuildId>) -> Vec<String> {
+    let (emergency_commands, mention_map) = {
+        let data = ctx.data.read().await;
+        let emergency_commands = data.get::<EmergencyCommands>().cloned().unwrap();
+        let mention_map = data.get::<MentionCommandMap>().cloned().unwrap();
+
+        (emergency_commands, mention_map)
+    };
+
+    let mut mention_commands = emergency_commands.to_vec();
+
+    if let Some(guild_commands) = guild_id.and_then(|guild_id| mention_map.get(&guild_id)) {
+        mention_commands.extend(guild_commands.iter().cloned());
+    }
+
+    mention_commands
+}
+
+// Prefer the longest match so prefixes like `j` and `j!` can coexist
+pub fn get_matching_prefix<'a>(prefixes: &'a [String], content: &str) -> Option<&'a String> {
+    prefixes
+        .iter()
+        .filter(|prefix| content.starts_with(prefix.as_str()))
+        .max_by_key(|prefix| prefix.len())
+}
+
+// The text after the bot mention or the prefix, ex. `play song` for `j!play song`
+pub async fn get_invocation<'a>(ctx: &Context, msg: &'a Message) -> Option<&'a str> {
+    if let Some(invocation) = strip_mention_prefix(&msg.content) {
+        return Some(invocation);
+    }
+
+    let prefixes = get_prefixes(ctx, msg.guild_id).await;
+    let prefix = get_matching_prefix(&prefixes, &msg.content)?;
+
+    msg.content.strip_prefix(prefix.as_str())
+}
+
+// Subcommands belong to their parent command, so only the first word after the prefix is needed
+pub async fn get_command_location(ctx: &Context, msg: &Message) -> Option<CommandLocation> {
+    let group_map = ctx
+        .data
+        .read()
+        .await
+        .get::<CommandGroupMap>()
+        .cloned()
+        .unwrap();
+
+    let invoked = get_invocation(ctx, msg).await?.split_whitespace().next()?;
+
+    group_map.get(invoked).cloned()
+}
+
+// Group names can contain spaces, so strip them to allow passing a group as a single argument
+pub fn normalize_group_name(name: &str) -> String {
+    name.to_lowercase().replace(' ', "")
+}
+
+// Every command name and alias, so custom commands can't shadow them
+pub fn get_command_names(groups: &[&'static CommandGroup]) -> Vec<String> {
+    groups
+        .iter()
+        .flat_map(|group| group.options.commands.iter())
+        .flat_map(|command| command.options.names.iter().map(ToString::to_string))
+        .collect()
+}
+
+/*
+ * Maps every command name and alias to the command's primary name, group and bucket
+ * Used to resolve what a user invoked when checking command rules and cooldowns
+ */
+pub fn get_command_group_map(groups: &[&'static CommandGroup]) -> HashMap<String, CommandLocation> {
+    let mut group_map = HashMap::new();
+
+    for group in groups {
+        let group_name = normalize_group_name(group.name);
+
+        for command in group.options.commands {
+            let command_name = command.options.names[0].to_owned();
+
+            for name in command.options.names {
+                group_map.insert(
+                    name.to_string(),
+                    CommandLocation {
+                        command: command_name.clone(),
+                        group: group_name.clone(),
+                        bucket: command.options.bucket,
+                    },
+                );
+            }
+        }
+    }
+
+    group_map
+}
+
+pub async fn send_reply(ctx: &Context, msg: &Message, reply: CommandReply) -> CommandResult {
+    match reply {
+        CommandReply::Text(content) => {
+            msg.channel_id.say(ctx, content).await?;
+        }
+        CommandReply::Embed(eb) => {
+            msg.channel_id
+                .send_message(ctx, |m| {
+                    m.embed(|e| {
+                        e.0 = eb.0;
+                        e
+                    })
+                })
+                .await?;
+        }
+        CommandReply::Reaction(reaction) => {
+            msg.react(ctx, reaction).await?;
+        }
+    }
+
+    Ok(())
+}
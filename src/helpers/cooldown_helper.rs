@@ -0,0 +1,348 @@
+use dashmap::DashMap;
+use serenity::{
+    client::Context,
+    model::{
+        channel::Message,
+        id::{ChannelId, GuildId, UserId},
+    },
+};
+use std::time::{Duration, Instant};
+
+use crate::{
+    helpers::command_utils,
+    structures::{CommandLocation, CooldownOverride, CooldownScope, TrackedUses},
+    CooldownMap, CooldownTracker,
+};
+
+// The longest cooldown that can be configured, one day
+pub const MAX_COOLDOWN_SECONDS: u64 = 86400;
+
+pub struct DefaultBucket {
+    pub name: &'static str,
+    pub delay: u64,
+    pub time_span: u64,
+    pub limit: u32,
+}
+
+/*
+ * Default cooldowns, shared by the framework buckets and slash commands
+ * A limit of 0 means only the delay applies
+ */
+pub const DEFAULT_BUCKETS: [DefaultBucket; 2] = [
+    DefaultBucket {
+        name: "images",
+        delay: 3,
+        time_span: 30,
+        limit: 5,
+    },
+    DefaultBucket {
+        name: "music",
+        delay: 2,
+        time_span: 0,
+        limit: 0,
+    },
+];
+
+async fn get_cooldown_override(
+    ctx: &Context,
+    guild_id: GuildId,
+    command: &str,
+) -> Option<CooldownOverride> {
+    let cooldown_map = ctx.data.read().await.get::<CooldownMap>().cloned().unwrap();
+
+    let cooldowns = cooldown_map.get(&guild_id)?;
+    let cooldown = cooldowns.get(command)?;
+
+    Some(*cooldown)
+}
+
+// Guild overrides replace the default bucket of a command
+pub async fn has_cooldown_override(ctx: &Context, msg: &Message) -> bool {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return false,
+    };
+
+    match command_utils::get_command_location(ctx, msg).await {
+        Some(location) => get_cooldown_override(ctx, guild_id, &location.command)
+            .await
+            .is_some(),
+        None => false,
+    }
+}
+
+pub async fn check_cooldown(ctx: &Context, msg: &Message) -> Option<u64> {
+    let guild_id = msg.guild_id?;
+    let location = command_utils::get_command_location(ctx, msg).await?;
+
+    check_command_cooldown(
+        ctx,
+        guild_id,
+        msg.channel_id,
+        msg.author.id,
+        location.command,
+    )
+    .await
+}
+
+/*
+ * Checks the guild's cooldown override for the invoked command
+ * Returns the amount of seconds left if the command is on cooldown
+ * Otherwise, the use is recorded and None is returned
+ */
+pub async fn check_command_cooldown(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    user_id: UserId,
+    command: String,
+) -> Option<u64> {
+    let cooldown = get_cooldown_override(ctx, guild_id, &command).await?;
+
+    let scope_id = match cooldown.scope {
+        CooldownScope::User => user_id.0,
+        CooldownScope::Channel => channel_id.0,
+        CooldownScope::Guild => guild_id.0,
+    };
+
+    record_use(
+        ctx,
+        (guild_id, command, scope_id),
+        cooldown.uses,
+        Duration::from_secs(cooldown.seconds),
+    )
+    .await
+}
+
+/*
+ * Slash commands bypass the framework, so its buckets are applied here when the guild has no override
+ * Buckets are per user across every guild, like the framework's
+ */
+pub async fn check_interaction_cooldown(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    user_id: UserId,
+    location: &CommandLocation,
+) -> Option<u64> {
+    if let Some(guild_id) = guild_id {
+        if get_cooldown_override(ctx, guild_id, &location.command)
+            .await
+            .is_some()
+        {
+            return check_command_cooldown(
+                ctx,
+                guild_id,
+                channel_id,
+                user_id,
+                location.command.clone(),
+            )
+            .await;
+        }
+    }
+
+    check_bucket_cooldown(ctx, user_id, location.bucket?).await
+}
+
+async fn check_bucket_cooldown(ctx: &Context, user_id: UserId, bucket: &str) -> Option<u64> {
+    let bucket = DEFAULT_BUCKETS.iter().find(|x| x.name == bucket)?;
+
+    if bucket.limit > 0 {
+        if let Some(remaining) = record_use(
+            ctx,
+            (GuildId(0), format!("bucket {}", bucket.name), user_id.0),
+            bucket.limit,
+            Duration::from_secs(bucket.time_span),
+        )
+        .await
+        {
+            return Some(remaining);
+        }
+    }
+
+    record_use(
+        ctx,
+        (
+            GuildId(0),
+            format!("bucket {} delay", bucket.name),
+            user_id.0,
+        ),
+        1,
+        Duration::from_secs(bucket.delay),
+    )
+    .await
+}
+
+// Custom commands can only be limited to one use per user
+pub async fn check_custom_cooldown(
+    ctx: &Context,
+    guild_id: GuildId,
+    user_id: UserId,
+    command: String,
+    seconds: u64,
+) -> Option<u64> {
+    record_use(
+        ctx,
+        (guild_id, command, user_id.0),
+        1,
+        Duration::from_secs(seconds),
+    )
+    .await
+}
+
+// Auto-responders are keyed by ID, which can't clash with a command name since it has a space
+pub async fn check_autoresponse_cooldown(
+    ctx: &Context,
+    guild_id: GuildId,
+    channel_id: ChannelId,
+    id: i32,
+    seconds: u64,
+) -> Option<u64> {
+    record_use(
+        ctx,
+        (guild_id, format!("autoresponse {}", id), channel_id.0),
+        1,
+        Duration::from_secs(seconds),
+    )
+    .await
+}
+
+async fn record_use(
+    ctx: &Context,
+    key: (GuildId, String, u64),
+    max_uses: u32,
+    window: Duration,
+) -> Option<u64> {
+    let tracker = ctx
+        .data
+        .read()
+        .await
+        .get::<CooldownTracker>()
+        .cloned()
+        .unwrap();
+
+    record_use_at(&tracker, key, max_uses, window, Instant::now())
+}
+
+// Split from record_use so the window logic doesn't depend on the clock
+fn record_use_at(
+    tracker: &DashMap<(GuildId, String, u64), TrackedUses>,
+    key: (GuildId, String, u64),
+    max_uses: u32,
+    window: Duration,
+    now: Instant,
+) -> Option<u64> {
+    let mut tracked = tracker.entry(key).or_insert_with(|| TrackedUses {
+        uses: Vec::new(),
+        window,
+    });
+    tracked.window = window;
+    tracked
+        .uses
+        .retain(|used| now.duration_since(*used) < window);
+
+    if tracked.uses.len() >= max_uses as usize {
+        let remaining = window - now.duration_since(tracked.uses[0]);
+
+        Some(remaining.as_secs_f64().ceil() as u64)
+    } else {
+        tracked.uses.push(now);
+
+        None
+    }
+}
+
+// Entries with no uses left in their window are dropped so the tracker doesn't keep every user
+pub fn prune_tracker(tracker: &DashMap<(GuildId, String, u64), TrackedUses>) {
+    tracker.retain(|_, tracked| {
+        tracked
+            .uses
+            .last()
+            .map_or(false, |used| used.elapsed() < tracked.window)
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(name: &str) -> (GuildId, String, u64) {
+        (GuildId(1), name.to_owned(), 2)
+    }
+
+    #[test]
+    fn allows_uses_up_to_the_limit() {
+        let tracker = DashMap::new();
+        let window = Duration::from_secs(10);
+        let start = Instant::now();
+
+        assert_eq!(record_use_at(&tracker, key("a"), 2, window, start), None);
+        assert_eq!(record_use_at(&tracker, key("a"), 2, window, start), None);
+        assert_eq!(
+            record_use_at(&tracker, key("a"), 2, window, start),
+            Some(10)
+        );
+    }
+
+    #[test]
+    fn remaining_time_counts_from_the_oldest_use() {
+        let tracker = DashMap::new();
+        let window = Duration::from_secs(10);
+        let start = Instant::now();
+
+        record_use_at(&tracker, key("a"), 1, window, start);
+
+        let later = start + Duration::from_millis(3500);
+        assert_eq!(record_use_at(&tracker, key("a"), 1, window, later), Some(7));
+    }
+
+    #[test]
+    fn uses_expire_after_the_window() {
+        let tracker = DashMap::new();
+        let window = Duration::from_secs(10);
+        let start = Instant::now();
+
+        record_use_at(&tracker, key("a"), 1, window, start);
+
+        let after_window = start + window;
+        assert_eq!(
+            record_use_at(&tracker, key("a"), 1, window, after_window),
+            None
+        );
+        assert_eq!(tracker.get(&key("a")).unwrap().uses, vec![after_window]);
+    }
+
+    #[test]
+    fn keys_are_tracked_separately() {
+        let tracker = DashMap::new();
+        let window = Duration::from_secs(10);
+        let start = Instant::now();
+
+        record_use_at(&tracker, key("a"), 1, window, start);
+
+        assert_eq!(record_use_at(&tracker, key("b"), 1, window, start), None);
+        assert!(record_use_at(&tracker, key("a"), 1, window, start).is_some());
+    }
+
+    #[test]
+    fn prune_drops_expired_and_empty_entries() {
+        let tracker = DashMap::new();
+        let now = Instant::now();
+
+        record_use_at(&tracker, key("active"), 1, Duration::from_secs(60), now);
+        record_use_at(&tracker, key("expired"), 1, Duration::from_secs(0), now);
+        tracker.insert(
+            key("empty"),
+            TrackedUses {
+                uses: Vec::new(),
+                window: Duration::from_secs(60),
+            },
+        );
+
+        prune_tracker(&tracker);
+
+        assert!(tracker.contains_key(&key("active")));
+        assert!(!tracker.contains_key(&key("expired")));
+        assert!(!tracker.contains_key(&key("empty")));
+    }
+}
@@ -0,0 +1,371 @@
+use rand::seq::SliceRandom;
+use serenity::{
+    builder::ParseValue,
+    client::Context,
+    framework::standard::{Args, CommandResult},
+    model::{
+        channel::Message,
+        id::{ChannelId, RoleId},
+    },
+    utils::{parse_channel, parse_role},
+};
+
+use crate::{
+    helpers::{
+        command_utils, cooldown_helper, embed_store, locale_helper,
+        template_helper::{self, TemplateContext, TemplateError},
+    },
+    structures::{CustomResponse, Locale},
+    ConnectionPool, CustomCommandMap,
+};
+
+// Orders that `command list` can sort by
+pub const SORT_ORDERS: [&str; 4] = ["name", "uses", "new", "old"];
+
+#[derive(Clone, Debug)]
+pub enum ResponseError {
+    Json(String),
+    Empty,
+    InvalidUrl(String),
+    Template(TemplateError),
+}
+
+impl ResponseError {
+    pub fn localize(&self, locale: &Locale) -> String {
+        match self {
+            ResponseError::Json(error) => {
+                locale.format("command.invalid_json", &[("error", error)])
+            }
+            ResponseError::Empty => locale.get("command.empty_response"),
+            ResponseError::InvalidUrl(url) => locale.format("command.invalid_url", &[("url", url)]),
+            ResponseError::Template(e) => e.localize(locale),
+        }
+    }
+}
+
+// Who can use a custom command, where, and how often. Empty lists mean no restriction
+#[derive(Clone, Debug, Default)]
+pub struct CommandRestrictions {
+    pub roles: Vec<RoleId>,
+    pub channels: Vec<ChannelId>,
+    pub cooldown: Option<u64>,
+}
+
+impl CommandRestrictions {
+    pub fn role_ids(&self) -> Vec<i64> {
+        self.roles.iter().map(|role_id| role_id.0 as i64).collect()
+    }
+
+    pub fn channel_ids(&self) -> Vec<i64> {
+        self.channels
+            .iter()
+            .map(|channel_id| channel_id.0 as i64)
+            .collect()
+    }
+}
+
+/*
+ * Reads restriction options in front of a custom command's content
+ * ex. `role:@Mod channel:#memes cooldown:30`. Options can be repeated for more roles or channels
+ * Returns the option that couldn't be read on failure
+ */
+pub fn parse_restrictions(args: &mut Args) -> Result<CommandRestrictions, String> {
+    let mut restrictions = CommandRestrictions::default();
+
+    while let Some(option) = args.current() {
+        let option = option.to_owned();
+
+        if let Some(role) = option.strip_prefix("role:") {
+            let role_id = parse_role(role).or_else(|| role.parse::<u64>().ok());
+
+            match role_id {
+                Some(role_id) => restrictions.roles.push(RoleId(role_id)),
+                None => return Err(option),
+            }
+        } else if let Some(channel) = option.strip_prefix("channel:") {
+            let channel_id = parse_channel(channel).or_else(|| channel.parse::<u64>().ok());
+
+            match channel_id {
+                Some(channel_id) => restrictions.channels.push(ChannelId(channel_id)),
+                None => return Err(option),
+            }
+        } else if let Some(seconds) = option.strip_prefix("cooldown:") {
+            match seconds.parse::<u64>() {
+                Ok(seconds) if seconds > 0 && seconds <= cooldown_helper::MAX_COOLDOWN_SECONDS => {
+                    restrictions.cooldown = Some(seconds)
+                }
+                _ => return Err(option),
+            }
+        } else {
+            break;
+        }
+
+        args.advance();
+    }
+
+    Ok(restrictions)
+}
+
+// Everything after the command name, ex. `@someone hi` for `j!hug @someone hi`
+async fn get_command_args<'a>(ctx: &Context, msg: &'a Message, command_name: &str) -> &'a str {
+    command_utils::get_invocation(ctx, msg)
+        .await
+        .and_then(|invocation| invocation.trim_start().strip_prefix(command_name))
+        .map_or("", |args| args.trim())
+}
+
+/*
+ * Runs a guild's custom command, which counts as a use for the {count} placeholder
+ * Role, channel, and cooldown restrictions are checked first and don't count as a use
+ * Returns false if the guild doesn't have a command with this name
+ */
+pub async fn run_custom_command(
+    ctx: &Context,
+    msg: &Message,
+    command_name: &str,
+) -> CommandResult<bool> {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(false),
+    };
+
+    let (pool, custom_command_map) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let custom_command_map = data.get::<CustomCommandMap>().cloned().unwrap();
+
+        (pool, custom_command_map)
+    };
+
+    // Unknown commands and typos are answered from the cache without touching the database
+    let command = match custom_command_map
+        .get(&guild_id)
+        .and_then(|commands| commands.get(command_name).cloned())
+    {
+        Some(command) => command,
+        None => return Ok(false),
+    };
+
+    let locale = locale_helper::get_locale(ctx, Some(guild_id)).await;
+
+    let in_channel = command.channels.is_empty() || command.channels.contains(&msg.channel_id);
+
+    let has_role = command.roles.is_empty()
+        || msg.member.as_ref().map_or(false, |member| {
+            member
+                .roles
+                .iter()
+                .any(|role_id| command.roles.contains(role_id))
+        });
+
+    if !in_channel || !has_role {
+        msg.channel_id
+            .say(ctx, locale.get("framework.disabled"))
+            .await?;
+
+        return Ok(true);
+    }
+
+    if let Some(seconds) = command.cooldown {
+        let remaining = cooldown_helper::check_custom_cooldown(
+            ctx,
+            guild_id,
+            msg.author.id,
+            command_name.to_owned(),
+            seconds,
+        )
+        .await;
+
+        if let Some(remaining) = remaining {
+            msg.channel_id
+                .say(
+                    ctx,
+                    locale.format("framework.cooldown", &[("seconds", &remaining)]),
+                )
+                .await?;
+
+            return Ok(true);
+        }
+    }
+
+    // The cached count is what {count} shows, and the database keeps it for restarts
+    let count = custom_command_map
+        .get_mut(&guild_id)
+        .and_then(|mut commands| {
+            commands.get_mut(command_name).map(|command| {
+                command.uses += 1;
+                command.uses
+            })
+        })
+        .unwrap_or(command.uses + 1);
+
+    sqlx::query!(
+        "UPDATE commands SET uses = uses + 1 WHERE guild_id = $1 AND name = $2",
+        guild_id.0 as i64,
+        command_name
+    )
+    .execute(&pool)
+    .await?;
+
+    // Plain commands are a single response with only text
+    let responses = match command.responses {
+        Some(responses) => responses,
+        None => vec![CustomResponse {
+            content: command.content,
+            ..Default::default()
+        }],
+    };
+
+    let response = match responses.choose(&mut rand::thread_rng()) {
+        Some(response) => response,
+        None => return Ok(true),
+    };
+
+    let server = guild_id.name(ctx).await.unwrap_or_default();
+    let args = get_command_args(ctx, msg, command_name).await;
+
+    let context = TemplateContext {
+        author: &msg.author,
+        channel_id: msg.channel_id,
+        args,
+        server: &server,
+        count,
+    };
+
+    let mut output = response
+        .content
+        .as_deref()
+        .map_or_else(String::new, |content| render_text(content, &context));
+
+    // Attachments are sent as links for Discord to embed, so the bot never downloads a user's URL
+    for url in &response.attachments {
+        if !output.is_empty() {
+            output.push('\n');
+        }
+
+        output.push_str(url);
+    }
+
+    let embed = response
+        .embed
+        .as_ref()
+        .map(|embed| embed_store::get_custom_embed(embed, |text| render_text(text, &context)));
+
+    if output.trim().is_empty() && embed.is_none() {
+        return Ok(true);
+    }
+
+    // Only users can be pinged, so a command can't be used to mention @everyone or a role
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.content(output);
+
+            if let Some(embed) = embed {
+                m.set_embed(embed);
+            }
+            m.allowed_mentions(|am| am.empty_parse().parse(ParseValue::Users))
+        })
+        .await?;
+
+    Ok(true)
+}
+
+// Commands saved before templates were validated are sent as they were written
+fn render_text(text: &str, context: &TemplateContext) -> String {
+    match template_helper::parse_template(text) {
+        Ok(parts) => template_helper::render_template(&parts, context),
+        Err(_) => text.to_owned(),
+    }
+}
+
+/*
+ * Reads rich responses from JSON. Either one response or a list of them can be given
+ * Every response needs something to send, and its text has to be a valid template
+ */
+pub fn parse_responses(json: &str) -> Result<Vec<CustomResponse>, ResponseError> {
+    let responses = match serde_json::from_str::<Vec<CustomResponse>>(json) {
+        Ok(responses) => responses,
+        Err(_) => vec![serde_json::from_str::<CustomResponse>(json)
+            .map_err(|e| ResponseError::Json(e.to_string()))?],
+    };
+
+    validate_responses(&responses)?;
+
+    Ok(responses)
+}
+
+pub fn validate_responses(responses: &[CustomResponse]) -> Result<(), ResponseError> {
+    if responses.is_empty() {
+        return Err(ResponseError::Empty);
+    }
+
+    for response in responses {
+        if response.content.is_none() && response.embed.is_none() && response.attachments.is_empty()
+        {
+            return Err(ResponseError::Empty);
+        }
+
+        if let Some(url) = get_urls(response).find(|url| !is_url(url)) {
+            return Err(ResponseError::InvalidUrl(url.to_owned()));
+        }
+
+        for text in get_template_text(response) {
+            template_helper::parse_template(text).map_err(ResponseError::Template)?;
+        }
+    }
+
+    Ok(())
+}
+
+// Categories are a single word, so they can be told apart from sort orders in `command list`
+pub fn is_valid_category(category: &str) -> bool {
+    !category.is_empty()
+        && category.chars().count() <= 32
+        && !category.contains(char::is_whitespace)
+        && !SORT_ORDERS.contains(&category)
+}
+
+// JSON is usually pasted in a code block, ex. ```json {...} ```
+pub fn strip_code_block(json: &str) -> &str {
+    json.trim()
+        .trim_start_matches("```json")
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim()
+}
+
+// Discord fetches embedded links itself, but only over https
+fn is_url(url: &str) -> bool {
+    url.starts_with("https://") && url.len() > "https://".len()
+}
+
+fn get_urls(response: &CustomResponse) -> impl Iterator<Item = &String> {
+    let embed_urls = response.embed.iter().flat_map(|embed| {
+        embed
+            .url
+            .iter()
+            .chain(embed.image.iter())
+            .chain(embed.thumbnail.iter())
+    });
+
+    response.attachments.iter().chain(embed_urls)
+}
+
+fn get_template_text(response: &CustomResponse) -> Vec<&str> {
+    let mut text = Vec::new();
+
+    text.extend(response.content.as_deref());
+
+    if let Some(embed) = &response.embed {
+        text.extend(embed.title.as_deref());
+        text.extend(embed.description.as_deref());
+        text.extend(embed.footer.as_deref());
+
+        for field in &embed.fields {
+            text.push(&field.name);
+            text.push(&field.value);
+        }
+    }
+
+    text
+}
@@ -1,6 +1,18 @@
-use dashmap::DashMap;
-use serenity::{framework::standard::CommandResult, model::id::GuildId};
+use dashmap::{DashMap, DashSet};
+use serenity::{
+    framework::standard::CommandResult,
+    model::{
+        channel::ReactionType,
+        id::{ChannelId, GuildId, RoleId},
+    },
+};
 use sqlx::postgres::{PgPool, PgPoolOptions};
+use std::{collections::HashMap, convert::TryFrom};
+
+use crate::structures::{
+    AutoResponder, BlockTarget, CommandRule, CooldownOverride, CooldownScope, CustomCommand,
+    PermissionLevel, RuleScope, Starboard, StarboardFilters, TriggerType,
+};
 
 pub async fn obtain_db_pool(db_connection: String) -> CommandResult<PgPool> {
     let connection_string = &db_connection;
@@ -13,18 +25,363 @@ pub async fn obtain_db_pool(db_connection: String) -> CommandResult<PgPool> {
     Ok(pool)
 }
 
-pub async fn fetch_prefixes(pool: &PgPool) -> CommandResult<DashMap<GuildId, String>> {
-    let prefixes: DashMap<GuildId, String> = DashMap::new();
+pub async fn fetch_prefixes(pool: &PgPool) -> CommandResult<DashMap<GuildId, Vec<String>>> {
+    let prefixes: DashMap<GuildId, Vec<String>> = DashMap::new();
 
-    let cursor = sqlx::query!("SELECT guild_id, prefix FROM guild_info")
+    let cursor = sqlx::query!("SELECT guild_id, prefixes FROM guild_info")
         .fetch_all(pool)
         .await?;
 
     for i in cursor {
-        if let Some(prefix) = i.prefix {
-            prefixes.insert(GuildId::from(i.guild_id as u64), prefix);
+        if let Some(guild_prefixes) = i.prefixes {
+            if !guild_prefixes.is_empty() {
+                prefixes.insert(GuildId::from(i.guild_id as u64), guild_prefixes);
+            }
         }
     }
 
     Ok(prefixes)
 }
+
+pub async fn fetch_languages(pool: &PgPool) -> CommandResult<DashMap<GuildId, String>> {
+    let languages: DashMap<GuildId, String> = DashMap::new();
+
+    let cursor =
+        sqlx::query!("SELECT guild_id, language FROM guild_info WHERE language IS NOT NULL")
+            .fetch_all(pool)
+            .await?;
+
+    for i in cursor {
+        if let Some(language) = i.language {
+            languages.insert(GuildId::from(i.guild_id as u64), language);
+        }
+    }
+
+    Ok(languages)
+}
+
+pub async fn fetch_command_rules(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, Vec<CommandRule>>> {
+    let rules: DashMap<GuildId, Vec<CommandRule>> = DashMap::new();
+
+    let cursor = sqlx::query!(
+        "SELECT guild_id, name, is_group, scope_type, scope_id, enabled FROM command_rules"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        let rule = CommandRule {
+            name: i.name,
+            is_group: i.is_group,
+            scope: RuleScope::from_db(&i.scope_type, i.scope_id),
+            enabled: i.enabled,
+        };
+
+        rules
+            .entry(GuildId::from(i.guild_id as u64))
+            .or_insert_with(Vec::new)
+            .push(rule);
+    }
+
+    Ok(rules)
+}
+
+pub async fn fetch_cooldowns(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, HashMap<String, CooldownOverride>>> {
+    let cooldowns: DashMap<GuildId, HashMap<String, CooldownOverride>> = DashMap::new();
+
+    // Older versions could store wrapped negative limits, those are skipped
+    let cursor = sqlx::query!(
+        "SELECT guild_id, name, scope_type, uses, seconds FROM command_cooldowns
+            WHERE uses > 0 AND seconds > 0"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        let cooldown = CooldownOverride {
+            scope: CooldownScope::from_db(&i.scope_type),
+            uses: i.uses as u32,
+            seconds: i.seconds as u64,
+        };
+
+        cooldowns
+            .entry(GuildId::from(i.guild_id as u64))
+            .or_insert_with(HashMap::new)
+            .insert(i.name, cooldown);
+    }
+
+    Ok(cooldowns)
+}
+
+pub async fn fetch_aliases(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, HashMap<String, String>>> {
+    let aliases: DashMap<GuildId, HashMap<String, String>> = DashMap::new();
+
+    let cursor = sqlx::query!("SELECT guild_id, alias, command FROM command_aliases")
+        .fetch_all(pool)
+        .await?;
+
+    for i in cursor {
+        aliases
+            .entry(GuildId::from(i.guild_id as u64))
+            .or_insert_with(HashMap::new)
+            .insert(i.alias, i.command);
+    }
+
+    Ok(aliases)
+}
+
+pub async fn fetch_permission_roles(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, HashMap<PermissionLevel, Vec<RoleId>>>> {
+    let permission_roles: DashMap<GuildId, HashMap<PermissionLevel, Vec<RoleId>>> = DashMap::new();
+
+    let cursor = sqlx::query!("SELECT guild_id, level, role_id FROM permission_roles")
+        .fetch_all(pool)
+        .await?;
+
+    for i in cursor {
+        if let Some(level) = PermissionLevel::from_name(&i.level) {
+            permission_roles
+                .entry(GuildId::from(i.guild_id as u64))
+                .or_insert_with(HashMap::new)
+                .entry(level)
+                .or_insert_with(Vec::new)
+                .push(RoleId::from(i.role_id as u64));
+        }
+    }
+
+    Ok(permission_roles)
+}
+
+pub async fn fetch_blocklist(pool: &PgPool) -> CommandResult<DashSet<BlockTarget>> {
+    let blocklist: DashSet<BlockTarget> = DashSet::new();
+
+    let cursor = sqlx::query!("SELECT target_type, target_id FROM blocklist")
+        .fetch_all(pool)
+        .await?;
+
+    for i in cursor {
+        if let Some(target) = BlockTarget::from_db(&i.target_type, i.target_id) {
+            blocklist.insert(target);
+        }
+    }
+
+    Ok(blocklist)
+}
+
+pub async fn fetch_mention_commands(pool: &PgPool) -> CommandResult<DashMap<GuildId, Vec<String>>> {
+    let mention_commands: DashMap<GuildId, Vec<String>> = DashMap::new();
+
+    let cursor = sqlx::query!(
+        "SELECT guild_id, mention_commands FROM guild_info WHERE cardinality(mention_commands) > 0"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        mention_commands.insert(GuildId::from(i.guild_id as u64), i.mention_commands);
+    }
+
+    Ok(mention_commands)
+}
+
+pub async fn fetch_autoresponders(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, Vec<AutoResponder>>> {
+    let autoresponders: DashMap<GuildId, Vec<AutoResponder>> = DashMap::new();
+
+    let cursor = sqlx::query!(
+        "SELECT response_id, guild_id, trigger_type, trigger, response, reaction, cooldown, channel_ids
+            FROM autoresponses ORDER BY response_id"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        let trigger_type = match TriggerType::from_name(&i.trigger_type) {
+            Some(trigger_type) => trigger_type,
+            None => continue,
+        };
+
+        // Triggers are checked when they're added, so this only happens if the regex crate changes
+        let matcher = match trigger_type.compile(&i.trigger) {
+            Ok(matcher) => matcher,
+            Err(e) => {
+                eprintln!(
+                    "Skipping auto-responder {} with an invalid trigger! {}",
+                    i.response_id, e
+                );
+                continue;
+            }
+        };
+
+        autoresponders
+            .entry(GuildId::from(i.guild_id as u64))
+            .or_insert_with(Vec::new)
+            .push(AutoResponder {
+                id: i.response_id,
+                trigger_type,
+                trigger: i.trigger,
+                matcher,
+                response: i.response,
+                reaction: i.reaction,
+                // Cooldowns saved before they were capped may have wrapped below 0
+                cooldown: i
+                    .cooldown
+                    .filter(|seconds| *seconds > 0)
+                    .map(|seconds| seconds as u64),
+                channels: i
+                    .channel_ids
+                    .into_iter()
+                    .map(|channel_id| ChannelId::from(channel_id as u64))
+                    .collect(),
+            });
+    }
+
+    Ok(autoresponders)
+}
+
+pub async fn fetch_custom_commands(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, HashMap<String, CustomCommand>>> {
+    let custom_commands: DashMap<GuildId, HashMap<String, CustomCommand>> = DashMap::new();
+
+    let cursor = sqlx::query!(
+        "SELECT guild_id, name, content, responses::text AS responses, allowed_roles, allowed_channels, cooldown, uses
+            FROM commands"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        let command = CustomCommand::from_db(
+            i.content,
+            i.responses,
+            i.allowed_roles,
+            i.allowed_channels,
+            i.cooldown,
+            i.uses,
+        );
+
+        custom_commands
+            .entry(GuildId::from(i.guild_id as u64))
+            .or_insert_with(HashMap::new)
+            .insert(i.name, command);
+    }
+
+    Ok(custom_commands)
+}
+
+pub async fn fetch_guild_custom_commands(
+    pool: &PgPool,
+    guild_id: GuildId,
+) -> CommandResult<HashMap<String, CustomCommand>> {
+    let cursor = sqlx::query!(
+        "SELECT name, content, responses::text AS responses, allowed_roles, allowed_channels, cooldown, uses
+            FROM commands WHERE guild_id = $1",
+        guild_id.0 as i64
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let custom_commands = cursor
+        .into_iter()
+        .map(|i| {
+            let command = CustomCommand::from_db(
+                i.content,
+                i.responses,
+                i.allowed_roles,
+                i.allowed_channels,
+                i.cooldown,
+                i.uses,
+            );
+
+            (i.name, command)
+        })
+        .collect();
+
+    Ok(custom_commands)
+}
+
+pub async fn fetch_suggestion_opt_outs(pool: &PgPool) -> CommandResult<DashSet<GuildId>> {
+    let opt_outs: DashSet<GuildId> = DashSet::new();
+
+    let cursor = sqlx::query!("SELECT guild_id FROM guild_info WHERE NOT suggestions")
+        .fetch_all(pool)
+        .await?;
+
+    for i in cursor {
+        opt_outs.insert(GuildId::from(i.guild_id as u64));
+    }
+
+    Ok(opt_outs)
+}
+
+pub async fn fetch_starboards(pool: &PgPool) -> CommandResult<DashMap<GuildId, Vec<Starboard>>> {
+    let starboards: DashMap<GuildId, Vec<Starboard>> = DashMap::new();
+
+    let cursor = sqlx::query!(
+        "SELECT guild_id, name, emoji, threshold, channel_id FROM starboards ORDER BY name"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        // Emojis are checked when boards are set up, so this only happens if the format changes
+        let emoji = match ReactionType::try_from(i.emoji.as_str()) {
+            Ok(emoji) => emoji,
+            Err(_) => {
+                eprintln!(
+                    "Skipping starboard {} in guild {} with an invalid emoji!",
+                    i.name, i.guild_id
+                );
+                continue;
+            }
+        };
+
+        starboards
+            .entry(GuildId::from(i.guild_id as u64))
+            .or_insert_with(Vec::new)
+            .push(Starboard {
+                name: i.name,
+                emoji,
+                threshold: i.threshold as u32,
+                channel_id: ChannelId::from(i.channel_id as u64),
+            });
+    }
+
+    Ok(starboards)
+}
+
+pub async fn fetch_starboard_filters(
+    pool: &PgPool,
+) -> CommandResult<DashMap<GuildId, StarboardFilters>> {
+    let filters: DashMap<GuildId, StarboardFilters> = DashMap::new();
+
+    let cursor = sqlx::query!(
+        "SELECT guild_id, starboard_ignore_self, starboard_ignore_bots, starboard_ignore_bot_stars
+            FROM guild_info
+            WHERE starboard_ignore_self OR starboard_ignore_bots OR starboard_ignore_bot_stars"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    for i in cursor {
+        filters.insert(
+            GuildId::from(i.guild_id as u64),
+            StarboardFilters {
+                ignore_self: i.starboard_ignore_self,
+                ignore_bots: i.starboard_ignore_bots,
+                ignore_bot_stars: i.starboard_ignore_bot_stars,
+            },
+        );
+    }
+
+    Ok(filters)
+}
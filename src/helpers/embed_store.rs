@@ -1,20 +1,20 @@
 use serenity::builder::CreateEmbed;
 
-use crate::structures::{AnimeResult, MangaResult};
+use crate::structures::{AnimeResult, CustomEmbed, Locale, MangaResult};
 
-pub fn get_result_embed(result_string: &str) -> CreateEmbed {
+pub fn get_result_embed(result_string: &str, locale: &Locale) -> CreateEmbed {
     let mut eb = CreateEmbed::default();
 
-    eb.description(format!("Here are your results \n\n```{}```", result_string));
+    eb.description(locale.format("japan.results", &[("results", &result_string)]));
     eb.footer(|f| {
-        f.text("Pick a number from the list. \nType abort if you want to cancel");
+        f.text(locale.get("japan.results_footer"));
         f
     });
 
     eb
 }
 
-pub fn get_anime_embed(anime: &AnimeResult) -> CreateEmbed {
+pub fn get_anime_embed(anime: &AnimeResult, locale: &Locale) -> CreateEmbed {
     let mut eb = CreateEmbed::default();
 
     eb.color(0xff004c);
@@ -22,20 +22,24 @@ pub fn get_anime_embed(anime: &AnimeResult) -> CreateEmbed {
     eb.url(&anime.url);
     eb.thumbnail(&anime.image_url);
     eb.description(&anime.synopsis);
-    eb.field("Episodes", anime.episodes, true);
+    eb.field(locale.get("japan.episodes"), anime.episodes, true);
 
     if anime.airing {
-        eb.field("Status", "Airing", true);
+        eb.field(locale.get("japan.status"), locale.get("japan.airing"), true);
     } else {
-        eb.field("Status", "CMP", true);
+        eb.field(
+            locale.get("japan.status"),
+            locale.get("japan.completed"),
+            true,
+        );
     }
 
-    eb.field("MAL Score", &anime.score, true);
+    eb.field(locale.get("japan.score"), &anime.score, true);
 
     eb
 }
 
-pub fn get_manga_embed(manga: &MangaResult) -> CreateEmbed {
+pub fn get_manga_embed(manga: &MangaResult, locale: &Locale) -> CreateEmbed {
     let mut eb = CreateEmbed::default();
 
     eb.color(0x00e1ff);
@@ -45,24 +49,127 @@ pub fn get_manga_embed(manga: &MangaResult) -> CreateEmbed {
     eb.description(&manga.synopsis);
 
     if manga.chapters == 0 {
-        eb.field("Chapters", "Unknown", true);
+        eb.field(
+            locale.get("japan.chapters"),
+            locale.get("japan.unknown"),
+            true,
+        );
     } else {
-        eb.field("Chapters", manga.chapters, true);
+        eb.field(locale.get("japan.chapters"), manga.chapters, true);
     }
 
     if manga.volumes == 0 {
-        eb.field("Volumes", "Unknown", true);
+        eb.field(
+            locale.get("japan.volumes"),
+            locale.get("japan.unknown"),
+            true,
+        );
     } else {
-        eb.field("Volumes", manga.volumes, true);
+        eb.field(locale.get("japan.volumes"), manga.volumes, true);
     }
 
     if manga.publishing {
-        eb.field("Status", "Publishing", true);
+        eb.field(
+            locale.get("japan.status"),
+            locale.get("japan.publishing"),
+            true,
+        );
     } else {
-        eb.field("Status", "CMP", true);
+        eb.field(
+            locale.get("japan.status"),
+            locale.get("japan.completed"),
+            true,
+        );
     }
 
-    eb.field("MAL Score", &manga.score, true);
+    eb.field(locale.get("japan.score"), &manga.score, true);
+
+    eb
+}
+
+pub fn get_support_embed(locale: &Locale) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    eb.title(locale.get("support.title"));
+    eb.description(locale.get("support.description"));
+    eb.field(
+        locale.get("support.server"),
+        "https://discord.gg/pswt7by",
+        false,
+    );
+    eb.field(
+        locale.get("support.repository"),
+        "https://github.com/bdashore3/courtjester",
+        false,
+    );
+    eb.field(
+        locale.get("support.twitter"),
+        "https://twitter.com/kingbri1st",
+        false,
+    );
+    eb.footer(|f| {
+        f.text(locale.get("support.footer"));
+        f
+    });
+
+    eb
+}
+
+// Shared by text and slash commands. The error ID is the only detail shown
+pub fn get_error_embed(cmd_name: &str, error_id: &str, locale: &Locale) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    eb.color(0xff69b4);
+    eb.title(locale.get("framework.error_title"));
+    eb.description(locale.get("framework.error_description"));
+    eb.field(locale.get("framework.command_name"), cmd_name, false);
+    eb.field(
+        locale.get("framework.error_id"),
+        format!("`{}`", error_id),
+        false,
+    );
+
+    eb
+}
+
+// Text in custom command embeds is passed through the command's placeholders first
+pub fn get_custom_embed(embed: &CustomEmbed, render: impl Fn(&str) -> String) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    if let Some(color) = embed.color {
+        eb.color(color);
+    }
+
+    if let Some(title) = &embed.title {
+        eb.title(render(title));
+    }
+
+    if let Some(url) = &embed.url {
+        eb.url(url);
+    }
+
+    if let Some(description) = &embed.description {
+        eb.description(render(description));
+    }
+
+    if let Some(image) = &embed.image {
+        eb.image(image);
+    }
+
+    if let Some(thumbnail) = &embed.thumbnail {
+        eb.thumbnail(thumbnail);
+    }
+
+    for field in &embed.fields {
+        eb.field(render(&field.name), render(&field.value), field.inline);
+    }
+
+    if let Some(footer) = &embed.footer {
+        eb.footer(|f| {
+            f.text(render(footer));
+            f
+        });
+    }
 
     eb
 }
@@ -0,0 +1,103 @@
+use serenity::{
+    client::Context,
+    framework::standard::{CommandError, CommandResult},
+    model::{
+        channel::Message,
+        id::{ChannelId, GuildId, UserId},
+    },
+};
+use std::error::Error;
+use uuid::Uuid;
+
+use crate::{helpers::command_utils, ConnectionPool};
+
+// Walks the error's sources so the root cause is stored along with the top level error
+pub fn get_error_chain(error: &(dyn Error + 'static)) -> String {
+    let mut chain = error.to_string();
+    let mut source = error.source();
+
+    while let Some(cause) = source {
+        chain.push_str(&format!("\nCaused by: {}", cause));
+        source = cause.source();
+    }
+
+    chain
+}
+
+// Everything after the invoked command name. Falls back to the whole message if no prefix matches
+async fn get_command_args(ctx: &Context, msg: &Message) -> String {
+    // Covers the bot mention, guild prefixes and the default prefix in DMs
+    let invocation = match command_utils::get_invocation(ctx, msg).await {
+        Some(invocation) => invocation,
+        None => return msg.content.to_owned(),
+    };
+
+    invocation
+        .trim_start()
+        .splitn(2, char::is_whitespace)
+        .nth(1)
+        .unwrap_or_default()
+        .trim()
+        .to_owned()
+}
+
+/*
+ * Stores the full error in the database and returns its ID
+ * Only the ID is shown to the user, so internals aren't leaked into the channel
+ */
+pub async fn log_command_error(
+    ctx: &Context,
+    msg: &Message,
+    cmd_name: &str,
+    error: &CommandError,
+) -> CommandResult<Uuid> {
+    let arguments = get_command_args(ctx, msg).await;
+
+    store_command_error(
+        ctx,
+        msg.guild_id,
+        msg.channel_id,
+        msg.author.id,
+        cmd_name,
+        &arguments,
+        error,
+    )
+    .await
+}
+
+// Slash commands pass their options as the arguments
+pub async fn store_command_error(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    user_id: UserId,
+    cmd_name: &str,
+    arguments: &str,
+    error: &CommandError,
+) -> CommandResult<Uuid> {
+    let pool = ctx
+        .data
+        .read()
+        .await
+        .get::<ConnectionPool>()
+        .cloned()
+        .unwrap();
+
+    let error_id = Uuid::new_v4();
+
+    sqlx::query!(
+        "INSERT INTO command_errors(error_id, guild_id, channel_id, user_id, command, arguments, error)
+            VALUES($1, $2, $3, $4, $5, $6, $7)",
+        error_id,
+        guild_id.map(|x| x.0 as i64),
+        channel_id.0 as i64,
+        user_id.0 as i64,
+        cmd_name,
+        arguments,
+        get_error_chain(&**error)
+    )
+    .execute(&pool)
+    .await?;
+
+    Ok(error_id)
+}
@@ -0,0 +1,305 @@
+use serenity::{
+    builder::CreateEmbed,
+    framework::standard::{Command, CommandGroup},
+};
+
+use crate::{
+    helpers::command_utils,
+    structures::{Locale, PermissionLevel},
+};
+
+// Groups with help disabled only hold helper commands, such as the last message variants of text commands
+pub fn get_help_groups(groups: &[&'static CommandGroup]) -> Vec<&'static CommandGroup> {
+    groups
+        .iter()
+        .filter(|group| group.options.help_available)
+        .copied()
+        .collect()
+}
+
+pub fn find_group(groups: &[&'static CommandGroup], name: &str) -> Option<&'static CommandGroup> {
+    let name = command_utils::normalize_group_name(name);
+
+    get_help_groups(groups)
+        .into_iter()
+        .find(|group| command_utils::normalize_group_name(group.name) == name)
+}
+
+/*
+ * Resolves a command from the words it's invoked with, ex. `config cooldown set`
+ * Also returns the full name of the command so subcommands are shown the way they're typed
+ */
+pub fn find_command(
+    groups: &[&'static CommandGroup],
+    words: &[String],
+) -> Option<(&'static Command, String)> {
+    let (first, rest) = words.split_first()?;
+
+    let mut command = get_help_groups(groups)
+        .into_iter()
+        .flat_map(|group| group.options.commands.iter().copied())
+        .find(|command| command.options.names.contains(&first.as_str()))?;
+    let mut path = vec![command.options.names[0]];
+
+    for word in rest {
+        command = command
+            .options
+            .sub_commands
+            .iter()
+            .copied()
+            .find(|sub_command| sub_command.options.names.contains(&word.as_str()))?;
+        path.push(command.options.names[0]);
+    }
+
+    if !command.options.help_available {
+        return None;
+    }
+
+    Some((command, path.join(" ")))
+}
+
+// English descriptions come from the command attributes, other languages can override them in their catalog
+fn get_description(locale: &Locale, path: &str, command: &Command) -> String {
+    locale.get_or(
+        &format!("description.{}", path.replace(' ', ".")),
+        command.options.desc.unwrap_or_default(),
+    )
+}
+
+fn get_group_description(locale: &Locale, group: &CommandGroup) -> String {
+    locale.get_or(
+        &format!("group.{}", command_utils::normalize_group_name(group.name)),
+        group.options.description.unwrap_or_default(),
+    )
+}
+
+fn get_signature(path: &str, command: &Command) -> String {
+    match command.options.usage {
+        Some(usage) => format!("{} {}", path, usage),
+        None => path.to_owned(),
+    }
+}
+
+/*
+ * Permission flags are shown as `MANAGE_EMOJIS`, which are translated like the permission error fragments
+ * Permission level checks are named after their level, ex. `Moderator`
+ */
+fn get_permission_names(locale: &Locale, command: &Command) -> Vec<String> {
+    let mut names = command
+        .options
+        .checks
+        .iter()
+        .filter_map(|check| PermissionLevel::from_name(&check.name.to_lowercase()))
+        .map(|level| {
+            locale.format(
+                "help.level",
+                &[
+                    ("level", &locale.get(&format!("level.{}", level.as_str()))),
+                    ("permission", &locale.get(level.permission_name())),
+                ],
+            )
+        })
+        .collect::<Vec<String>>();
+
+    let permissions = command.options.required_permissions;
+
+    if !permissions.is_empty() {
+        names.extend(
+            format!("{:?}", permissions)
+                .split(" | ")
+                .map(|name| locale.get(&name.to_lowercase().replace('_', " "))),
+        );
+    }
+
+    names
+}
+
+fn get_visible_commands(commands: &[&'static Command]) -> Vec<&'static Command> {
+    commands
+        .iter()
+        .filter(|command| command.options.help_available)
+        .copied()
+        .collect()
+}
+
+pub fn get_default_help_embed(locale: &Locale, groups: &[&'static CommandGroup]) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    eb.title(locale.get("help.default.title"));
+    eb.description(locale.get("help.default.description"));
+
+    for group in get_help_groups(groups) {
+        let command_names = get_visible_commands(group.options.commands)
+            .iter()
+            .map(|command| format!("`{}`", command.options.names[0]))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        eb.field(
+            group.name,
+            format!(
+                "{}\n{}",
+                get_group_description(locale, group),
+                command_names
+            ),
+            false,
+        );
+    }
+
+    eb.footer(|f| {
+        f.text(locale.get("help.default.footer"));
+        f
+    });
+
+    eb
+}
+
+pub fn get_emergency_help_embed(
+    locale: &Locale,
+    groups: &[&'static CommandGroup],
+    emergency_commands: &[String],
+) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    let command_list = emergency_commands
+        .iter()
+        .filter(|name| name.as_str() != "help")
+        .filter_map(|name| find_command(groups, &[name.to_owned()]))
+        .filter(|(command, _)| !command.options.owners_only)
+        .map(|(command, path)| {
+            format!(
+                "{}: {}",
+                get_signature(&path, command),
+                get_description(locale, &path, command)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("\n\n");
+
+    eb.title(locale.get("help.emergency.title"));
+    eb.description(locale.get("help.emergency.description"));
+    eb.field(locale.get("help.commands"), command_list, false);
+
+    eb
+}
+
+pub fn get_group_help_embed(locale: &Locale, group: &CommandGroup) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    eb.title(locale.format("help.group.title", &[("group", &group.name)]));
+    eb.description(get_group_description(locale, group));
+
+    for command in get_visible_commands(group.options.commands) {
+        let path = command.options.names[0];
+        let mut info = get_description(locale, path, command);
+
+        if command.options.names.len() > 1 {
+            info.push_str(&format!(
+                "\n{}",
+                locale.format(
+                    "help.aliases_line",
+                    &[("aliases", &command.options.names[1..].join(", "))]
+                )
+            ));
+        }
+
+        let sub_commands = get_visible_commands(command.options.sub_commands);
+
+        if !sub_commands.is_empty() {
+            let sub_names = sub_commands
+                .iter()
+                .map(|sub_command| format!("`{}`", sub_command.options.names[0]))
+                .collect::<Vec<String>>()
+                .join(" ");
+
+            info.push_str(&format!(
+                "\n{}",
+                locale.format("help.subcommands_line", &[("subcommands", &sub_names)])
+            ));
+        }
+
+        eb.field(get_signature(path, command), info, false);
+    }
+
+    eb.footer(|f| {
+        f.text(locale.get("help.group.footer"));
+        f
+    });
+
+    eb
+}
+
+pub fn get_command_help_embed(locale: &Locale, command: &Command, path: &str) -> CreateEmbed {
+    let mut eb = CreateEmbed::default();
+
+    eb.title(locale.format("help.command.title", &[("command", &path)]));
+    eb.description(get_description(locale, path, command));
+    eb.field(
+        locale.get("help.usage"),
+        format!("`{}`", get_signature(path, command)),
+        false,
+    );
+
+    if !command.options.examples.is_empty() {
+        let examples = command
+            .options
+            .examples
+            .iter()
+            .map(|example| format!("`{} {}`", path, example))
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        eb.field(locale.get("help.examples"), examples, false);
+    }
+
+    if command.options.names.len() > 1 {
+        eb.field(
+            locale.get("help.aliases"),
+            command.options.names[1..].join(", "),
+            false,
+        );
+    }
+
+    let permissions = get_permission_names(locale, command);
+
+    if command.options.owners_only {
+        eb.field(
+            locale.get("help.permissions"),
+            locale.get("help.owners_only"),
+            false,
+        );
+    } else if !permissions.is_empty() {
+        eb.field(
+            locale.get("help.permissions"),
+            permissions.join(", "),
+            false,
+        );
+    }
+
+    let sub_commands = get_visible_commands(command.options.sub_commands);
+
+    if !sub_commands.is_empty() {
+        let sub_list = sub_commands
+            .iter()
+            .map(|sub_command| {
+                let sub_path = format!("{} {}", path, sub_command.options.names[0]);
+
+                format!(
+                    "`{}`: {}",
+                    get_signature(&sub_path, sub_command),
+                    get_description(locale, &sub_path, sub_command)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        eb.field(locale.get("help.subcommands"), sub_list, false);
+    }
+
+    eb.footer(|f| {
+        f.text(locale.get("help.parameters"));
+        f
+    });
+
+    eb
+}
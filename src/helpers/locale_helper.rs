@@ -0,0 +1,60 @@
+use serenity::{client::Context, framework::standard::CommandResult, model::id::GuildId};
+use std::{collections::HashMap, fs, io::BufReader, path::Path};
+
+use crate::{
+    structures::{Locale, LocaleCatalog},
+    GuildLanguageMap, Locales,
+};
+
+/*
+ * Loads every <language code>.json file in the locales directory
+ * English is required since every other language falls back to it
+ */
+pub fn load_locales(path: &str) -> CommandResult<LocaleCatalog> {
+    let mut catalog = LocaleCatalog::default();
+
+    for entry in fs::read_dir(path)? {
+        let file_path = entry?.path();
+
+        if file_path.extension().and_then(|x| x.to_str()) != Some("json") {
+            continue;
+        }
+
+        let language = match file_path.file_stem().and_then(|x| x.to_str()) {
+            Some(language) => language.to_owned(),
+            None => continue,
+        };
+
+        let reader = BufReader::new(fs::File::open(&file_path)?);
+        let messages: HashMap<String, String> = serde_json::from_reader(reader)?;
+
+        catalog.languages.insert(language, messages);
+    }
+
+    if !catalog.languages.contains_key("en") {
+        return Err(format!(
+            "The English catalog is missing from {}!",
+            Path::new(path).join("en.json").display()
+        )
+        .into());
+    }
+
+    Ok(catalog)
+}
+
+// Guilds without a language setting and DMs use English
+pub async fn get_locale(ctx: &Context, guild_id: Option<GuildId>) -> Locale {
+    let (catalog, languages) = {
+        let data = ctx.data.read().await;
+        let catalog = data.get::<Locales>().cloned().unwrap();
+        let languages = data.get::<GuildLanguageMap>().cloned().unwrap();
+
+        (catalog, languages)
+    };
+
+    let language = guild_id
+        .and_then(|guild_id| languages.get(&guild_id).map(|x| x.value().to_owned()))
+        .unwrap_or_else(|| "en".to_owned());
+
+    Locale { catalog, language }
+}
@@ -1,10 +1,22 @@
+pub mod alias_helper;
+pub mod autoresponse_helper;
+pub mod blocklist_helper;
 pub mod botinfo;
 pub mod command_utils;
+pub mod cooldown_helper;
 pub mod credentials_helper;
+pub mod custom_command_helper;
 pub mod database_helper;
 pub mod embed_store;
+pub mod error_helper;
+pub mod help_helper;
+pub mod locale_helper;
 pub mod permissions_helper;
 pub mod reaction_gifs;
+pub mod rules_helper;
 pub mod start_loops;
+pub mod stats_helper;
+pub mod suggestion_helper;
+pub mod template_helper;
 pub mod textmod_helper;
 pub mod voice_utils;
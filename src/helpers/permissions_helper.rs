@@ -1,39 +1,146 @@
-use serenity::{model::prelude::*, prelude::*};
+use serenity::{
+    framework::standard::{macros::check, Args, CommandOptions, Reason},
+    model::prelude::*,
+    prelude::*,
+};
 
-use crate::structures::errors::{JesterError, PermissionType};
+use crate::{
+    helpers::locale_helper,
+    structures::{
+        errors::{JesterError, PermissionType},
+        PermissionLevel,
+    },
+    PermissionRoleMap,
+};
 
+/*
+ * Administrators and members with the level's default permission always pass
+ * Otherwise, the member needs a role that's mapped to the level (or a higher one) with `config roles`
+ */
+pub async fn has_permission_level(
+    ctx: &Context,
+    guild_id: GuildId,
+    roles: &[RoleId],
+    permissions: Permissions,
+    level: PermissionLevel,
+) -> bool {
+    if permissions.administrator() || permissions.contains(level.default_permission()) {
+        return true;
+    }
+
+    let role_map = ctx
+        .data
+        .read()
+        .await
+        .get::<PermissionRoleMap>()
+        .cloned()
+        .unwrap();
+
+    let level_roles = match role_map.get(&guild_id) {
+        Some(level_roles) => level_roles,
+        None => return false,
+    };
+
+    level
+        .granted_by()
+        .iter()
+        .filter_map(|level| level_roles.get(level))
+        .flatten()
+        .any(|role_id| roles.contains(role_id))
+}
+
+async fn check_member_level(
+    ctx: &Context,
+    msg: &Message,
+    user_id: UserId,
+    level: PermissionLevel,
+) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(false),
+    };
+
+    // Falls back to fetching the channel if it isn't cached
+    let channel = match msg.channel_id.to_channel(ctx).await?.guild() {
+        Some(channel) => channel,
+        None => return Ok(false),
+    };
+    let permissions = channel.permissions_for_user(ctx, user_id).await?;
+    let member = guild_id.member(ctx, user_id).await?;
+
+    Ok(has_permission_level(ctx, guild_id, &member.roles, permissions, level).await)
+}
+
+/*
+ * Checks the author's permission level and tells them if it's missing
+ * If a user ID is given, that user is checked instead without sending anything
+ */
 pub async fn check_permission(
     ctx: &Context,
     msg: &Message,
     user_id: Option<UserId>,
-    check_admin: bool,
+    level: PermissionLevel,
 ) -> Result<bool, Box<dyn std::error::Error + Send + Sync>> {
-    let channel = msg.channel(ctx).await.unwrap().guild().unwrap();
-    let permissions = channel
-        .permissions_for_user(ctx, user_id.unwrap_or(msg.author.id))
-        .await?;
-
-    if permissions.administrator() {
-        return Ok(true);
-    } else if check_admin && user_id.is_none() {
+    let allowed = check_member_level(ctx, msg, user_id.unwrap_or(msg.author.id), level).await?;
+
+    if !allowed && user_id.is_none() {
+        let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
         msg.channel_id
             .say(
                 ctx,
-                JesterError::PermissionError(PermissionType::UserPerm("administrator")),
+                JesterError::PermissionError(PermissionType::Level(level)).localize(&locale),
             )
             .await?;
+    }
 
-        return Ok(false);
-    } else {
-        if user_id.is_none() && !permissions.manage_messages() {
-            msg.channel_id
-                .say(
-                    ctx,
-                    JesterError::PermissionError(PermissionType::UserPerm("manage messages")),
-                )
-                .await?;
-        }
+    Ok(allowed)
+}
 
-        return Ok(permissions.manage_messages());
+async fn check_level(ctx: &Context, msg: &Message, level: PermissionLevel) -> Result<(), Reason> {
+    match check_member_level(ctx, msg, msg.author.id, level).await {
+        Ok(true) => Ok(()),
+        Ok(false) => {
+            let locale = locale_helper::get_locale(ctx, msg.guild_id).await;
+
+            Err(Reason::User(
+                JesterError::PermissionError(PermissionType::Level(level)).localize(&locale),
+            ))
+        }
+        Err(e) => Err(Reason::Log(e.to_string())),
     }
 }
+
+// Framework checks for commands that used to require a fixed Discord permission
+#[check]
+#[name = "DJ"]
+async fn dj_check(
+    ctx: &Context,
+    msg: &Message,
+    _args: &mut Args,
+    _options: &CommandOptions,
+) -> Result<(), Reason> {
+    check_level(ctx, msg, PermissionLevel::Dj).await
+}
+
+#[check]
+#[name = "Moderator"]
+async fn moderator_check(
+    ctx: &Context,
+    msg: &Message,
+    _args: &mut Args,
+    _options: &CommandOptions,
+) -> Result<(), Reason> {
+    check_level(ctx, msg, PermissionLevel::Moderator).await
+}
+
+#[check]
+#[name = "Admin"]
+async fn admin_check(
+    ctx: &Context,
+    msg: &Message,
+    _args: &mut Args,
+    _options: &CommandOptions,
+) -> Result<(), Reason> {
+    check_level(ctx, msg, PermissionLevel::Admin).await
+}
@@ -0,0 +1,209 @@
+use serenity::{
+    client::Context,
+    model::{
+        channel::Message,
+        id::{ChannelId, RoleId},
+    },
+};
+
+use crate::{
+    helpers::command_utils,
+    structures::{CommandLocation, CommandRule, RuleScope},
+    CommandRuleMap,
+};
+
+/*
+ * Checks the guild's command rules for the invoked command
+ * Channel rules take precedence over role rules, which take precedence over guild-wide rules
+ * Within a scope, a rule for the command itself beats a rule for its group
+ */
+pub async fn check_command_rules(ctx: &Context, msg: &Message) -> bool {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return true,
+    };
+
+    let rule_map = ctx
+        .data
+        .read()
+        .await
+        .get::<CommandRuleMap>()
+        .cloned()
+        .unwrap();
+
+    let rules = match rule_map.get(&guild_id) {
+        Some(rules_guard) => rules_guard.value().clone(),
+        None => return true,
+    };
+
+    let location = match command_utils::get_command_location(ctx, msg).await {
+        Some(location) => location,
+        None => return true,
+    };
+
+    let roles = match &msg.member {
+        Some(member) => member.roles.clone(),
+        None => Vec::new(),
+    };
+
+    is_command_enabled(&rules, &location, msg.channel_id, &roles)
+}
+
+pub fn is_command_enabled(
+    rules: &[CommandRule],
+    location: &CommandLocation,
+    channel_id: ChannelId,
+    roles: &[RoleId],
+) -> bool {
+    let applicable = rules
+        .iter()
+        .filter(|rule| {
+            if rule.is_group {
+                rule.name == location.group
+            } else {
+                rule.name == location.command
+            }
+        })
+        .collect::<Vec<&CommandRule>>();
+
+    let channel_rules = applicable
+        .iter()
+        .filter(|rule| rule.scope == RuleScope::Channel(channel_id))
+        .copied()
+        .collect();
+
+    let role_rules = applicable
+        .iter()
+        .filter(|rule| matches!(rule.scope, RuleScope::Role(role_id) if roles.contains(&role_id)))
+        .copied()
+        .collect();
+
+    let guild_rules = applicable
+        .iter()
+        .filter(|rule| rule.scope == RuleScope::Guild)
+        .copied()
+        .collect();
+
+    resolve_scope(channel_rules)
+        .or_else(|| resolve_scope(role_rules))
+        .or_else(|| resolve_scope(guild_rules))
+        .unwrap_or(true)
+}
+
+// If a member has multiple roles with rules, one enabling rule is enough
+fn resolve_scope(rules: Vec<&CommandRule>) -> Option<bool> {
+    let (group_rules, command_rules): (Vec<&CommandRule>, Vec<&CommandRule>) =
+        rules.into_iter().partition(|rule| rule.is_group);
+
+    let deciding_rules = if command_rules.is_empty() {
+        group_rules
+    } else {
+        command_rules
+    };
+
+    if deciding_rules.is_empty() {
+        None
+    } else {
+        Some(deciding_rules.iter().any(|rule| rule.enabled))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CHANNEL: ChannelId = ChannelId(10);
+    const ROLE: RoleId = RoleId(20);
+
+    fn location() -> CommandLocation {
+        CommandLocation {
+            command: "play".to_owned(),
+            group: "music".to_owned(),
+            bucket: None,
+        }
+    }
+
+    fn rule(name: &str, is_group: bool, scope: RuleScope, enabled: bool) -> CommandRule {
+        CommandRule {
+            name: name.to_owned(),
+            is_group,
+            scope,
+            enabled,
+        }
+    }
+
+    #[test]
+    fn enabled_without_rules() {
+        assert!(is_command_enabled(&[], &location(), CHANNEL, &[ROLE]));
+    }
+
+    #[test]
+    fn rules_for_other_commands_are_ignored() {
+        let rules = [
+            rule("skip", false, RuleScope::Guild, false),
+            rule("images", true, RuleScope::Guild, false),
+        ];
+
+        assert!(is_command_enabled(&rules, &location(), CHANNEL, &[ROLE]));
+    }
+
+    #[test]
+    fn channel_beats_role_beats_guild() {
+        let guild_off = rule("play", false, RuleScope::Guild, false);
+        let role_on = rule("play", false, RuleScope::Role(ROLE), true);
+        let channel_off = rule("play", false, RuleScope::Channel(CHANNEL), false);
+
+        let rules = [guild_off.clone()];
+        assert!(!is_command_enabled(&rules, &location(), CHANNEL, &[ROLE]));
+
+        let rules = [guild_off.clone(), role_on.clone()];
+        assert!(is_command_enabled(&rules, &location(), CHANNEL, &[ROLE]));
+        assert!(!is_command_enabled(&rules, &location(), CHANNEL, &[]));
+
+        let rules = [guild_off, role_on, channel_off];
+        assert!(!is_command_enabled(&rules, &location(), CHANNEL, &[ROLE]));
+        assert!(is_command_enabled(
+            &rules,
+            &location(),
+            ChannelId(11),
+            &[ROLE]
+        ));
+    }
+
+    #[test]
+    fn command_rule_beats_group_rule_in_the_same_scope() {
+        let rules = [
+            rule("music", true, RuleScope::Guild, false),
+            rule("play", false, RuleScope::Guild, true),
+        ];
+
+        assert!(is_command_enabled(&rules, &location(), CHANNEL, &[]));
+    }
+
+    #[test]
+    fn group_rule_in_a_narrower_scope_beats_command_rule() {
+        let rules = [
+            rule("play", false, RuleScope::Guild, true),
+            rule("music", true, RuleScope::Channel(CHANNEL), false),
+        ];
+
+        assert!(!is_command_enabled(&rules, &location(), CHANNEL, &[]));
+    }
+
+    #[test]
+    fn one_enabling_role_is_enough() {
+        let other_role = RoleId(21);
+        let rules = [
+            rule("play", false, RuleScope::Role(ROLE), false),
+            rule("play", false, RuleScope::Role(other_role), true),
+        ];
+
+        assert!(is_command_enabled(
+            &rules,
+            &location(),
+            CHANNEL,
+            &[ROLE, other_role]
+        ));
+        assert!(!is_command_enabled(&rules, &location(), CHANNEL, &[ROLE]));
+    }
+}
@@ -1,4 +1,9 @@
-use crate::ConnectionPool;
+use crate::{
+    helpers::{cooldown_helper, stats_helper},
+    structures::{CommandUsage, TrackedUses},
+    ConnectionPool,
+};
+use dashmap::DashMap;
 use rand::{prelude::StdRng, Rng, SeedableRng};
 use serenity::{
     client::bridge::gateway::ShardMessenger,
@@ -7,13 +12,16 @@ use serenity::{
     prelude::*,
 };
 use sqlx::PgPool;
-use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{
+    sync::Arc,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 use tokio::time::sleep;
 
 pub async fn starboard_removal_loop(pool: &PgPool) -> CommandResult {
     loop {
         let delete_data = sqlx::query!(
-            "SELECT guild_id, reaction_message_id, sent_message_id, delete_time FROM starboard"
+            "SELECT guild_id, board_name, reaction_message_id, sent_message_id, delete_time FROM starboard_messages"
         )
         .fetch_all(pool)
         .await?;
@@ -34,8 +42,8 @@ pub async fn starboard_removal_loop(pool: &PgPool) -> CommandResult {
                     "Deleting starboard message {} from the database",
                     i.guild_id
                 );
-                sqlx::query!("DELETE FROM starboard WHERE guild_id = $1 AND reaction_message_id = $2 AND sent_message_id = $3",
-                        i.guild_id, i.reaction_message_id, i.sent_message_id)
+                sqlx::query!("DELETE FROM starboard_messages WHERE guild_id = $1 AND board_name = $2 AND reaction_message_id = $3 AND sent_message_id = $4",
+                        i.guild_id, i.board_name, i.reaction_message_id, i.sent_message_id)
                     .execute(pool).await?;
             } else {
                 println!(
@@ -51,6 +59,26 @@ pub async fn starboard_removal_loop(pool: &PgPool) -> CommandResult {
     }
 }
 
+// Command usage is buffered in memory and written in batches to avoid a query per command
+pub async fn usage_flush_loop(pool: &PgPool, buffer: Arc<Mutex<Vec<CommandUsage>>>) {
+    loop {
+        sleep(Duration::from_secs(60)).await;
+
+        if let Err(e) = stats_helper::flush_buffer(pool, &buffer).await {
+            eprintln!("Error when writing command usage! {}", e);
+        }
+    }
+}
+
+// Uses older than their window are dropped every 10 minutes
+pub async fn cooldown_prune_loop(tracker: Arc<DashMap<(GuildId, String, u64), TrackedUses>>) {
+    loop {
+        sleep(Duration::from_secs(600)).await;
+
+        cooldown_helper::prune_tracker(&tracker);
+    }
+}
+
 pub async fn guild_pruner(ctx: &Context) -> CommandResult {
     let pool = ctx
         .data
@@ -0,0 +1,224 @@
+use serenity::{
+    client::Context,
+    framework::standard::CommandResult,
+    model::{
+        channel::Message,
+        id::{ChannelId, GuildId, UserId},
+    },
+    prelude::Mutex,
+};
+use sqlx::PgPool;
+use std::{
+    mem,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    helpers::command_utils, structures::CommandUsage, CommandTimers, ConnectionPool, UsageBuffer,
+};
+
+const FLUSH_THRESHOLD: usize = 1000;
+const MAX_BUFFERED_USAGE: usize = 10000;
+
+/*
+ * Set while an early flush is running, and kept set if it fails
+ * This stops every command from starting a flush during a database outage
+ * Any successful flush clears it again
+ */
+static EARLY_FLUSH_BLOCKED: AtomicBool = AtomicBool::new(false);
+
+pub async fn start_timer(ctx: &Context, msg: &Message) {
+    let timers = ctx
+        .data
+        .read()
+        .await
+        .get::<CommandTimers>()
+        .cloned()
+        .unwrap();
+
+    timers.insert(msg.id, Instant::now());
+}
+
+/*
+ * Buffers a command invocation so it can be written with the next batch
+ * The root command is recorded so subcommands count towards their parent
+ */
+pub async fn record_usage(ctx: &Context, msg: &Message, cmd_name: &str, success: bool) {
+    let timers = ctx
+        .data
+        .read()
+        .await
+        .get::<CommandTimers>()
+        .cloned()
+        .unwrap();
+
+    let duration_ms = match timers.remove(&msg.id) {
+        Some((_, started)) => started.elapsed().as_millis() as u32,
+        None => 0,
+    };
+
+    let command = match command_utils::get_command_location(ctx, msg).await {
+        Some(location) => location.command,
+        None => cmd_name.to_owned(),
+    };
+
+    buffer_usage(
+        ctx,
+        msg.guild_id,
+        msg.channel_id,
+        msg.author.id,
+        command,
+        duration_ms,
+        success,
+    )
+    .await;
+}
+
+// Slash commands aren't messages, so their invocation is passed in directly
+pub async fn record_interaction_usage(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    user_id: UserId,
+    command: String,
+    started: Instant,
+    success: bool,
+) {
+    let duration_ms = started.elapsed().as_millis() as u32;
+
+    buffer_usage(
+        ctx,
+        guild_id,
+        channel_id,
+        user_id,
+        command,
+        duration_ms,
+        success,
+    )
+    .await;
+}
+
+// Once FLUSH_THRESHOLD invocations are buffered, they're written without waiting for the flush loop
+async fn buffer_usage(
+    ctx: &Context,
+    guild_id: Option<GuildId>,
+    channel_id: ChannelId,
+    user_id: UserId,
+    command: String,
+    duration_ms: u32,
+    success: bool,
+) {
+    let (pool, buffer) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let buffer = data.get::<UsageBuffer>().cloned().unwrap();
+
+        (pool, buffer)
+    };
+
+    let used_at = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Time went backwards?")
+        .as_secs();
+
+    let buffered = {
+        let mut usage = buffer.lock().await;
+
+        usage.push(CommandUsage {
+            guild_id,
+            channel_id,
+            user_id,
+            command,
+            duration_ms,
+            success,
+            used_at,
+        });
+        cap_usage(&mut usage);
+
+        usage.len()
+    };
+
+    if buffered >= FLUSH_THRESHOLD && !EARLY_FLUSH_BLOCKED.swap(true, Ordering::SeqCst) {
+        tokio::spawn(async move {
+            if let Err(e) = flush_buffer(&pool, &buffer).await {
+                eprintln!("Error when writing command usage! {}", e);
+            }
+        });
+    }
+}
+
+// If the database stays unreachable, the oldest usage is dropped so memory doesn't grow forever
+fn cap_usage(usage: &mut Vec<CommandUsage>) {
+    if usage.len() > MAX_BUFFERED_USAGE {
+        let excess = usage.len() - MAX_BUFFERED_USAGE;
+        usage.drain(..excess);
+    }
+}
+
+/*
+ * Writes everything in the buffer
+ * A failed batch is put back in front of newer usage so it's retried with the next flush
+ */
+pub async fn flush_buffer(pool: &PgPool, buffer: &Mutex<Vec<CommandUsage>>) -> CommandResult {
+    let usage = mem::take(&mut *buffer.lock().await);
+
+    if !usage.is_empty() {
+        if let Err(e) = flush_usage(pool, &usage).await {
+            let mut buffered = buffer.lock().await;
+            let newer = mem::replace(&mut *buffered, usage);
+            buffered.extend(newer);
+            cap_usage(&mut buffered);
+
+            return Err(e);
+        }
+    }
+
+    EARLY_FLUSH_BLOCKED.store(false, Ordering::SeqCst);
+
+    Ok(())
+}
+
+// Writes the whole batch in one query. A guild ID of 0 marks a DM and is stored as null
+async fn flush_usage(pool: &PgPool, usage: &[CommandUsage]) -> CommandResult {
+    let guild_ids = usage
+        .iter()
+        .map(|x| x.guild_id.map_or(0, |guild_id| guild_id.0 as i64))
+        .collect::<Vec<i64>>();
+    let channel_ids = usage
+        .iter()
+        .map(|x| x.channel_id.0 as i64)
+        .collect::<Vec<i64>>();
+    let user_ids = usage
+        .iter()
+        .map(|x| x.user_id.0 as i64)
+        .collect::<Vec<i64>>();
+    let commands = usage
+        .iter()
+        .map(|x| x.command.clone())
+        .collect::<Vec<String>>();
+    let durations = usage
+        .iter()
+        .map(|x| x.duration_ms as i32)
+        .collect::<Vec<i32>>();
+    let successes = usage.iter().map(|x| x.success).collect::<Vec<bool>>();
+    let used_times = usage.iter().map(|x| x.used_at as i64).collect::<Vec<i64>>();
+
+    sqlx::query!(
+        "INSERT INTO command_usage(guild_id, channel_id, user_id, command, duration_ms, success, used_at)
+            SELECT NULLIF(g, 0), c, u, n, d, s, to_timestamp(t)
+            FROM UNNEST($1::int8[], $2::int8[], $3::int8[], $4::text[], $5::int4[], $6::bool[], $7::int8[])
+            AS usage(g, c, u, n, d, s, t)",
+        &guild_ids,
+        &channel_ids,
+        &user_ids,
+        &commands,
+        &durations,
+        &successes,
+        &used_times
+    )
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+use serenity::{
+    client::Context,
+    framework::standard::CommandResult,
+    model::{channel::Message, id::ChannelId},
+};
+use std::time::{Duration, Instant};
+
+use crate::{
+    helpers::{command_utils, locale_helper},
+    AliasMap, CommandNameMap, CustomCommandMap, SuggestionOptOuts, SuggestionTracker,
+};
+
+// Only one suggestion is sent per channel in this window so typos don't flood the chat
+const SUGGESTION_COOLDOWN: Duration = Duration::from_secs(30);
+
+/*
+ * Edit distance where swapping two neighbouring letters counts as a single edit
+ * Swapped letters are the most common typo, ex. `plya` instead of `play`
+ */
+pub fn get_edit_distance(first: &str, second: &str) -> usize {
+    let a = first.chars().collect::<Vec<char>>();
+    let b = second.chars().collect::<Vec<char>>();
+
+    let mut distances = vec![vec![0; b.len() + 1]; a.len() + 1];
+
+    for (i, row) in distances.iter_mut().enumerate() {
+        row[0] = i;
+    }
+
+    for j in 0..=b.len() {
+        distances[0][j] = j;
+    }
+
+    for i in 1..=a.len() {
+        for j in 1..=b.len() {
+            let cost = if a[i - 1] == b[j - 1] { 0 } else { 1 };
+
+            distances[i][j] = (distances[i - 1][j] + 1)
+                .min(distances[i][j - 1] + 1)
+                .min(distances[i - 1][j - 1] + cost);
+
+            if i > 1 && j > 1 && a[i - 1] == b[j - 2] && a[i - 2] == b[j - 1] {
+                distances[i][j] = distances[i][j].min(distances[i - 2][j - 2] + 1);
+            }
+        }
+    }
+
+    distances[a.len()][b.len()]
+}
+
+/*
+ * Picks the closest name to what was typed
+ * Names that need too many edits for their length aren't likely typos, so they're skipped
+ */
+pub fn get_suggestion<'a>(
+    input: &str,
+    names: impl Iterator<Item = &'a String>,
+) -> Option<&'a String> {
+    let input = input.to_lowercase();
+
+    names
+        .map(|name| (name, get_edit_distance(&input, name)))
+        .filter(|(name, distance)| {
+            *distance > 0 && *distance <= 2 && distance * 2 <= name.chars().count()
+        })
+        .min_by_key(|(_, distance)| *distance)
+        .map(|(name, _)| name)
+}
+
+async fn is_on_cooldown(ctx: &Context, channel_id: ChannelId) -> bool {
+    let tracker = ctx
+        .data
+        .read()
+        .await
+        .get::<SuggestionTracker>()
+        .cloned()
+        .unwrap();
+
+    match tracker.get(&channel_id) {
+        Some(last_sent) => last_sent.elapsed() < SUGGESTION_COOLDOWN,
+        None => false,
+    }
+}
+
+/*
+ * Suggests the closest built-in command, custom command, or alias for an unrecognized command
+ * Nothing is sent if the guild turned suggestions off or the channel recently got one
+ */
+pub async fn send_suggestion(ctx: &Context, msg: &Message, command_name: &str) -> CommandResult {
+    let guild_id = match msg.guild_id {
+        Some(guild_id) => guild_id,
+        None => return Ok(()),
+    };
+
+    if is_on_cooldown(ctx, msg.channel_id).await {
+        return Ok(());
+    }
+
+    let (command_names, aliases, custom_commands, opt_outs, tracker) = {
+        let data = ctx.data.read().await;
+        let command_names = data.get::<CommandNameMap>().cloned().unwrap();
+        let aliases = data.get::<AliasMap>().cloned().unwrap();
+        let custom_commands = data.get::<CustomCommandMap>().cloned().unwrap();
+        let opt_outs = data.get::<SuggestionOptOuts>().cloned().unwrap();
+        let tracker = data.get::<SuggestionTracker>().cloned().unwrap();
+
+        (command_names, aliases, custom_commands, opt_outs, tracker)
+    };
+
+    if opt_outs.contains(&guild_id) {
+        return Ok(());
+    }
+
+    let mut custom_names = match custom_commands.get(&guild_id) {
+        Some(guild_commands) => guild_commands.keys().cloned().collect::<Vec<String>>(),
+        None => Vec::new(),
+    };
+
+    if let Some(guild_aliases) = aliases.get(&guild_id) {
+        custom_names.extend(guild_aliases.keys().cloned());
+    }
+
+    let suggestion = match get_suggestion(
+        command_name,
+        command_names.iter().chain(custom_names.iter()),
+    ) {
+        Some(suggestion) => suggestion,
+        None => return Ok(()),
+    };
+
+    let prefixes = command_utils::get_guild_prefixes(ctx, guild_id).await;
+    let prefix = command_utils::get_matching_prefix(&prefixes, &msg.content)
+        .cloned()
+        .unwrap_or_default();
+
+    let locale = locale_helper::get_locale(ctx, Some(guild_id)).await;
+
+    msg.channel_id
+        .say(
+            ctx,
+            locale.format(
+                "suggestion.did_you_mean",
+                &[
+                    ("command", &command_name),
+                    ("suggestion", &format!("{}{}", prefix, suggestion)),
+                ],
+            ),
+        )
+        .await?;
+
+    tracker.insert(msg.channel_id, Instant::now());
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distance_counts_basic_edits() {
+        assert_eq!(get_edit_distance("play", "play"), 0);
+        assert_eq!(get_edit_distance("play", "pla"), 1);
+        assert_eq!(get_edit_distance("play", "plays"), 1);
+        assert_eq!(get_edit_distance("play", "pray"), 1);
+        assert_eq!(get_edit_distance("", "skip"), 4);
+        assert_eq!(get_edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn edit_distance_counts_swaps_once() {
+        assert_eq!(get_edit_distance("plya", "play"), 1);
+        assert_eq!(get_edit_distance("qeueu", "queue"), 2);
+    }
+
+    #[test]
+    fn edit_distance_handles_multibyte_chars() {
+        assert_eq!(get_edit_distance("ヘルプ", "ヘルプ"), 0);
+        assert_eq!(get_edit_distance("ヘルプ", "ヘプル"), 1);
+    }
+
+    #[test]
+    fn suggestion_picks_the_closest_name() {
+        let names = vec!["play".to_owned(), "pause".to_owned(), "skip".to_owned()];
+
+        assert_eq!(get_suggestion("plya", names.iter()), Some(&names[0]));
+        assert_eq!(get_suggestion("SKIPP", names.iter()), Some(&names[2]));
+    }
+
+    #[test]
+    fn suggestion_skips_exact_and_distant_names() {
+        let names = vec!["play".to_owned(), "ping".to_owned()];
+
+        assert_eq!(get_suggestion("play", names.iter()), None);
+        assert_eq!(get_suggestion("starboard", names.iter()), None);
+    }
+}
@@ -0,0 +1,296 @@
+use rand::seq::SliceRandom;
+use serenity::{
+    model::{id::ChannelId, user::User},
+    prelude::Mentionable,
+    utils::parse_username,
+};
+use std::{
+    mem,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use crate::structures::Locale;
+
+pub const PLACEHOLDERS: &str = "{user} {args} {arg1} {author.name} {channel} {server} {mention:arg1} {random:a|b|c} {count} {time}";
+
+#[derive(Clone, Debug)]
+pub enum TemplatePart {
+    Text(String),
+    User,
+    Args,
+    Arg(usize),
+    AuthorName,
+    Channel,
+    Server,
+    Mention(usize),
+    Random(Vec<String>),
+    Count,
+    Time,
+}
+
+#[derive(Clone, Debug)]
+pub enum TemplateError {
+    Unclosed,
+    UnknownPlaceholder(String),
+    EmptyRandom,
+}
+
+impl TemplateError {
+    pub fn localize(&self, locale: &Locale) -> String {
+        match self {
+            TemplateError::Unclosed => locale.get("template.unclosed"),
+            TemplateError::UnknownPlaceholder(name) => locale.format(
+                "template.unknown",
+                &[("placeholder", name), ("placeholders", &PLACEHOLDERS)],
+            ),
+            TemplateError::EmptyRandom => locale.get("template.empty_random"),
+        }
+    }
+}
+
+// Everything the placeholders can be filled with
+pub struct TemplateContext<'a> {
+    pub author: &'a User,
+    pub channel_id: ChannelId,
+    pub args: &'a str,
+    pub server: &'a str,
+    pub count: i64,
+}
+
+// Argument placeholders start counting at 1, like they're shown to users
+fn parse_arg_index(name: &str) -> Option<usize> {
+    name.strip_prefix("arg")?
+        .parse::<usize>()
+        .ok()
+        .filter(|index| *index > 0)
+}
+
+fn parse_placeholder(name: &str) -> Result<TemplatePart, TemplateError> {
+    let part = match name {
+        "user" => TemplatePart::User,
+        "args" => TemplatePart::Args,
+        "author.name" => TemplatePart::AuthorName,
+        "channel" => TemplatePart::Channel,
+        "server" => TemplatePart::Server,
+        "count" => TemplatePart::Count,
+        "time" => TemplatePart::Time,
+        _ => {
+            if let Some(index) = parse_arg_index(name) {
+                TemplatePart::Arg(index)
+            } else if let Some(index) = name.strip_prefix("mention:").and_then(parse_arg_index) {
+                TemplatePart::Mention(index)
+            } else if let Some(options) = name.strip_prefix("random:") {
+                let options = options
+                    .split('|')
+                    .map(|option| option.to_owned())
+                    .collect::<Vec<String>>();
+
+                if options.iter().all(|option| option.is_empty()) {
+                    return Err(TemplateError::EmptyRandom);
+                }
+
+                TemplatePart::Random(options)
+            } else {
+                return Err(TemplateError::UnknownPlaceholder(name.to_owned()));
+            }
+        }
+    };
+
+    Ok(part)
+}
+
+/*
+ * Splits a custom command into text and placeholders
+ * Use {{ and }} for literal braces. A lone } is kept as text
+ */
+pub fn parse_template(content: &str) -> Result<Vec<TemplatePart>, TemplateError> {
+    let mut parts = Vec::new();
+    let mut text = String::new();
+    let mut chars = content.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                text.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                text.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => name.push(c),
+                        None => return Err(TemplateError::Unclosed),
+                    }
+                }
+
+                if !text.is_empty() {
+                    parts.push(TemplatePart::Text(mem::take(&mut text)));
+                }
+
+                parts.push(parse_placeholder(name.trim())?);
+            }
+            _ => text.push(c),
+        }
+    }
+
+    if !text.is_empty() {
+        parts.push(TemplatePart::Text(text));
+    }
+
+    Ok(parts)
+}
+
+fn get_arg<'a>(args: &'a str, index: usize) -> &'a str {
+    args.split_whitespace().nth(index - 1).unwrap_or_default()
+}
+
+// Users can be given as a mention or an ID. Anything else is shown as typed
+fn get_mention(arg: &str) -> String {
+    match parse_username(arg).or_else(|| arg.parse::<u64>().ok()) {
+        Some(user_id) => format!("<@{}>", user_id),
+        None => arg.to_owned(),
+    }
+}
+
+pub fn render_template(parts: &[TemplatePart], context: &TemplateContext) -> String {
+    let mut output = String::new();
+
+    for part in parts {
+        match part {
+            TemplatePart::Text(text) => output.push_str(text),
+            TemplatePart::User => output.push_str(&context.author.mention().to_string()),
+            TemplatePart::Args => output.push_str(context.args),
+            TemplatePart::Arg(index) => output.push_str(get_arg(context.args, *index)),
+            TemplatePart::AuthorName => output.push_str(&context.author.name),
+            TemplatePart::Channel => output.push_str(&context.channel_id.mention().to_string()),
+            TemplatePart::Server => output.push_str(context.server),
+            TemplatePart::Mention(index) => {
+                output.push_str(&get_mention(get_arg(context.args, *index)))
+            }
+            TemplatePart::Random(options) => {
+                if let Some(option) = options.choose(&mut rand::thread_rng()) {
+                    output.push_str(option);
+                }
+            }
+            TemplatePart::Count => output.push_str(&context.count.to_string()),
+            TemplatePart::Time => {
+                let timestamp = SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |duration| duration.as_secs());
+
+                // Discord shows timestamps in each reader's own timezone
+                output.push_str(&format!("<t:{}:f>", timestamp));
+            }
+        }
+    }
+
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(content: &str, args: &str) -> String {
+        let author = serde_json::from_value::<User>(serde_json::json!({
+            "id": "1",
+            "username": "jester",
+            "discriminator": "0001",
+            "avatar": null,
+        }))
+        .unwrap();
+
+        let context = TemplateContext {
+            author: &author,
+            channel_id: ChannelId(2),
+            args,
+            server: "Circus",
+            count: 3,
+        };
+
+        render_template(&parse_template(content).unwrap(), &context)
+    }
+
+    #[test]
+    fn parses_text_and_placeholders() {
+        let parts = parse_template("hi {user}, {arg2}!").unwrap();
+
+        assert!(matches!(&parts[..], [
+            TemplatePart::Text(start),
+            TemplatePart::User,
+            TemplatePart::Text(middle),
+            TemplatePart::Arg(2),
+            TemplatePart::Text(end),
+        ] if start == "hi " && middle == ", " && end == "!"));
+    }
+
+    #[test]
+    fn parses_escaped_and_lone_braces() {
+        let parts = parse_template("{{literal}} and }").unwrap();
+
+        assert!(matches!(&parts[..], [TemplatePart::Text(text)] if text == "{literal} and }"));
+    }
+
+    #[test]
+    fn parses_random_and_mention_placeholders() {
+        let parts = parse_template("{ random:a|b }{mention:arg1}").unwrap();
+
+        assert!(matches!(&parts[..], [
+            TemplatePart::Random(options),
+            TemplatePart::Mention(1),
+        ] if options == &["a", "b"]));
+    }
+
+    #[test]
+    fn rejects_invalid_templates() {
+        assert!(matches!(
+            parse_template("{user"),
+            Err(TemplateError::Unclosed)
+        ));
+        assert!(matches!(
+            parse_template("{random:|}"),
+            Err(TemplateError::EmptyRandom)
+        ));
+        assert!(matches!(
+            parse_template("{arg0}"),
+            Err(TemplateError::UnknownPlaceholder(name)) if name == "arg0"
+        ));
+        assert!(matches!(
+            parse_template("{nope}"),
+            Err(TemplateError::UnknownPlaceholder(name)) if name == "nope"
+        ));
+    }
+
+    #[test]
+    fn renders_context_placeholders() {
+        assert_eq!(
+            render("{user} {author.name} {channel} {server} #{count}", ""),
+            "<@1> jester <#2> Circus #3"
+        );
+    }
+
+    #[test]
+    fn renders_arguments() {
+        assert_eq!(render("{args}|{arg1}|{arg3}", "one two"), "one two|one|");
+        assert_eq!(
+            render(
+                "{mention:arg1} {mention:arg2} {mention:arg3}",
+                "<@!5> 6 someone"
+            ),
+            "<@5> <@6> someone"
+        );
+    }
+
+    #[test]
+    fn renders_one_random_option() {
+        let output = render("{random:a|b}", "");
+
+        assert!(output == "a" || output == "b");
+    }
+}
@@ -11,7 +11,11 @@ use serenity::{
 use std::time::Duration;
 use tokio::time::sleep;
 
-use crate::{BotId, Lavalink, VoiceTimerMap};
+use crate::{
+    helpers::{command_utils, locale_helper},
+    structures::CommandReply,
+    BotId, Lavalink, VoiceTimerMap,
+};
 
 pub async fn get_voice_state(
     ctx: &Context,
@@ -40,67 +44,63 @@ pub async fn get_voice_state(
     }
 }
 
+/// Forces the bot to join your voice chat
 #[command]
 #[aliases("connect")]
+#[only_in(guilds)]
 pub async fn summon(ctx: &Context, msg: &Message) -> CommandResult {
     let guild = msg.guild(ctx).await.unwrap();
+
+    let reply = summon_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn summon_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
     let bot_id = ctx.data.read().await.get::<BotId>().cloned().unwrap();
 
     if guild.voice_states.contains_key(&bot_id) {
-        msg.channel_id
-            .say(ctx, "Looks like I'm already in a voice channel! Please disconnect me before summoning me again!")
-            .await?;
-
-        return Ok(());
+        return Ok(CommandReply::Text(locale.get("voice.already_connected")));
     }
 
     let channel_id = guild
         .voice_states
-        .get(&msg.author.id)
+        .get(&author_id)
         .and_then(|voice_state| voice_state.channel_id);
 
     let voice_channel = match channel_id {
         Some(channel) => channel,
-        None => {
-            msg.channel_id
-                .say(ctx, "Please join a voice channel!")
-                .await?;
-
-            return Ok(());
-        }
+        None => return Ok(CommandReply::Text(locale.get("voice.join_channel"))),
     };
 
-    match join_voice_internal(ctx, msg, voice_channel).await {
+    match join_voice_internal(ctx, guild.id, voice_channel).await {
         Ok(_) => {
-            msg.channel_id
-                .say(
-                    ctx,
-                    format!("Joined {}", voice_channel.name(ctx).await.unwrap()),
-                )
-                .await?;
-
+            let guild_id = guild.id;
             let ctx_clone = ctx.clone();
             tokio::spawn(async move {
-                create_new_timer(ctx_clone, guild.id).await;
+                create_new_timer(ctx_clone, guild_id).await;
             });
+
+            Ok(CommandReply::Text(locale.format(
+                "voice.joined",
+                &[("channel", &voice_channel.name(ctx).await.unwrap())],
+            )))
         }
-        Err(_e) => {
-            msg.channel_id
-                .say(ctx, "I couldn't join the voice channel. Please check if I have permission to access it!")
-                .await?;
-        }
+        Err(_e) => Ok(CommandReply::Text(locale.get("voice.join_failed"))),
     }
-
-    Ok(())
 }
 
 pub async fn join_voice_internal(
     ctx: &Context,
-    msg: &Message,
+    guild_id: GuildId,
     voice_channel: ChannelId,
 ) -> CommandResult {
-    let guild_id = msg.guild_id.unwrap();
-
     let manager = songbird::get(ctx).await.unwrap().clone();
 
     let (_, handler) = manager.join_gateway(guild_id, voice_channel).await;
@@ -116,24 +116,29 @@ pub async fn join_voice_internal(
     Ok(())
 }
 
+/// Leaves the voice chat and clears everything
 #[command]
 #[aliases("dc")]
+#[only_in(guilds)]
 async fn disconnect(ctx: &Context, msg: &Message) -> CommandResult {
-    let guild_id = ctx
-        .cache
-        .guild_channel_field(msg.channel_id, |channel| channel.guild_id)
-        .await
-        .unwrap();
     let guild = msg.guild(ctx).await.unwrap();
 
-    if !get_voice_state(ctx, &guild, msg.author.id).await? {
-        msg.channel_id
-            .say(
-                ctx,
-                "Please be in a voice channel or in the same voice channel as me!",
-            )
-            .await?;
-        return Ok(());
+    let reply = disconnect_internal(ctx, &guild, msg.author.id).await?;
+    command_utils::send_reply(ctx, msg, reply).await?;
+
+    Ok(())
+}
+
+pub async fn disconnect_internal(
+    ctx: &Context,
+    guild: &Guild,
+    author_id: UserId,
+) -> CommandResult<CommandReply> {
+    let locale = locale_helper::get_locale(ctx, Some(guild.id)).await;
+    let guild_id = guild.id;
+
+    if !get_voice_state(ctx, guild, author_id).await? {
+        return Ok(CommandReply::Text(locale.get("music.voice_state")));
     }
 
     match leavevc_internal(ctx, guild_id).await {
@@ -153,16 +158,10 @@ async fn disconnect(ctx: &Context, msg: &Message) -> CommandResult {
                 voice_timer_map.remove(&guild_id);
             }
 
-            msg.channel_id.say(ctx, "Left the voice channel!").await?;
-        }
-        Err(_e) => {
-            msg.channel_id
-                .say(ctx, "The bot isn't in a voice channel!")
-                .await?;
+            Ok(CommandReply::Text(locale.get("voice.left")))
         }
+        Err(_e) => Ok(CommandReply::Text(locale.get("voice.not_connected"))),
     }
-
-    Ok(())
 }
 
 pub async fn leavevc_internal(ctx: &Context, guild_id: GuildId) -> CommandResult {
@@ -207,25 +206,3 @@ pub async fn create_new_timer(ctx: Context, guild_id: GuildId) {
 
     voice_timer_map.remove(&guild_id);
 }
-
-pub async fn voice_help(ctx: &Context, channel_id: ChannelId) {
-    let content = concat!(
-        "summon: Forces the bot to join the voice chat \nAlias: connect \n\n",
-        "disconnect: Leaves the voice chat and clears everything \n\n"
-    );
-
-    let _ = channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Voice Help");
-                e.description("Description: General commands for voice chat");
-                e.field("Commands", content, false);
-                e.footer(|f| {
-                    f.text("The user has to be in the voice chat on execution!");
-                    f
-                });
-                e
-            })
-        })
-        .await;
-}
@@ -0,0 +1,535 @@
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    framework::standard::CommandResult,
+    model::{
+        guild::Guild,
+        id::{ChannelId, GuildId},
+        interactions::{
+            ApplicationCommandInteractionData, ApplicationCommandInteractionDataOption,
+            ApplicationCommandInteractionDataOptionValue, Interaction, InteractionData,
+            InteractionResponseType,
+        },
+        user::User,
+        Permissions,
+    },
+};
+use std::{convert::TryFrom, time::Instant};
+
+use crate::{
+    commands::{
+        ciphers, images, japan, music,
+        starboard::{self, BoardChanges},
+        support, utility,
+    },
+    helpers::{
+        blocklist_helper, cooldown_helper, embed_store, error_helper, locale_helper,
+        permissions_helper, rules_helper, stats_helper, textmod_helper, voice_utils,
+    },
+    structures::{CommandReply, Locale, PermissionLevel},
+    CommandGroupMap, CommandRuleMap, JesterError, PermissionType,
+};
+
+// Starboards, images, voice and music need a guild. Every other slash command also works in DMs
+const GUILD_COMMANDS: [&str; 19] = [
+    "starboard",
+    "hug",
+    "pat",
+    "slap",
+    "kiss",
+    "disgust",
+    "cry",
+    "cringe",
+    "summon",
+    "disconnect",
+    "play",
+    "pause",
+    "resume",
+    "stop",
+    "skip",
+    "queue",
+    "remove",
+    "seek",
+    "clear",
+];
+
+// Commands that can take longer than Discord's initial response window
+const DEFERRED_COMMANDS: [&str; 13] = [
+    "gif", "hug", "pat", "slap", "kiss", "disgust", "cry", "cringe", "anime", "manga", "info",
+    "summon", "play",
+];
+
+pub async fn dispatch_interaction(ctx: &Context, interaction: &Interaction) -> CommandResult {
+    let data = match &interaction.data {
+        Some(InteractionData::ApplicationCommand(data)) => data,
+        _ => return Ok(()),
+    };
+
+    let user = match &interaction.member {
+        Some(member) => &member.user,
+        None => &interaction.user,
+    };
+
+    // Like text commands, blocked users are deliberately left without a response
+    if blocklist_helper::is_blocked(ctx, user.id, interaction.guild_id).await {
+        return Ok(());
+    }
+
+    let locale = locale_helper::get_locale(ctx, interaction.guild_id).await;
+
+    if GUILD_COMMANDS.contains(&data.name.as_str()) && interaction.guild_id.is_none() {
+        let reply = CommandReply::Text(locale.get("interactions.guild_only"));
+        return send_interaction_reply(ctx, interaction, reply, false).await;
+    }
+
+    let (rule_map, group_map) = {
+        let data = ctx.data.read().await;
+        let rule_map = data.get::<CommandRuleMap>().cloned().unwrap();
+        let group_map = data.get::<CommandGroupMap>().cloned().unwrap();
+
+        (rule_map, group_map)
+    };
+
+    // Slash commands are named after their text counterparts, so the same rules and cooldowns apply
+    let command = match group_map.get(&data.name) {
+        Some(location) => {
+            if let (Some(guild_id), Some(member)) = (interaction.guild_id, &interaction.member) {
+                let enabled = match rule_map.get(&guild_id) {
+                    Some(rules) => rules_helper::is_command_enabled(
+                        rules.value(),
+                        location,
+                        interaction.channel_id,
+                        &member.roles,
+                    ),
+                    None => true,
+                };
+
+                if !enabled {
+                    let reply = CommandReply::Text(locale.get("framework.disabled"));
+                    return send_interaction_reply(ctx, interaction, reply, false).await;
+                }
+            }
+
+            if let Some(remaining) = cooldown_helper::check_interaction_cooldown(
+                ctx,
+                interaction.guild_id,
+                interaction.channel_id,
+                user.id,
+                location,
+            )
+            .await
+            {
+                let reply = CommandReply::Text(
+                    locale.format("framework.cooldown", &[("seconds", &remaining)]),
+                );
+                return send_interaction_reply(ctx, interaction, reply, false).await;
+            }
+
+            location.command.clone()
+        }
+        None => data.name.clone(),
+    };
+
+    let deferred = DEFERRED_COMMANDS.contains(&data.name.as_str());
+
+    if deferred {
+        interaction
+            .create_interaction_response(&ctx.http, |r| {
+                r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+            })
+            .await?;
+    }
+
+    let started = Instant::now();
+    let result = run_interaction_command(ctx, interaction, data, user, &locale).await;
+
+    stats_helper::record_interaction_usage(
+        ctx,
+        interaction.guild_id,
+        interaction.channel_id,
+        user.id,
+        command,
+        started,
+        result.is_ok(),
+    )
+    .await;
+
+    let reply = match result {
+        Ok(reply) => reply,
+        Err(why) => {
+            let error_id = match error_helper::store_command_error(
+                ctx,
+                interaction.guild_id,
+                interaction.channel_id,
+                user.id,
+                &data.name,
+                &get_interaction_args(&data.options),
+                &why,
+            )
+            .await
+            {
+                Ok(error_id) => error_id.to_string(),
+                Err(e) => {
+                    eprintln!("Error when logging a command error! {}: {}", e, why);
+
+                    locale.get("framework.error_id_unavailable")
+                }
+            };
+
+            CommandReply::Embed(embed_store::get_error_embed(&data.name, &error_id, &locale))
+        }
+    };
+
+    send_interaction_reply(ctx, interaction, reply, deferred).await
+}
+
+async fn run_interaction_command(
+    ctx: &Context,
+    interaction: &Interaction,
+    data: &ApplicationCommandInteractionData,
+    user: &User,
+    locale: &Locale,
+) -> CommandResult<CommandReply> {
+    let reply = match data.name.as_str() {
+        "ping" => CommandReply::Text(locale.get("other.pong")),
+        "support" => CommandReply::Embed(embed_store::get_support_embed(locale)),
+        "help" => {
+            let words = get_string_option(&data.options, "query")
+                .split_whitespace()
+                .map(|word| word.to_lowercase())
+                .collect::<Vec<String>>();
+
+            support::get_help_reply(locale, &words)
+        }
+        "info" => CommandReply::Embed(support::get_info_embed(ctx, locale).await?),
+        "avatar" => {
+            let user = match get_user_option(&data.options, "user") {
+                Some(user) => user,
+                None => user.clone(),
+            };
+
+            CommandReply::Text(user.face())
+        }
+        "emoji_info" => {
+            utility::get_emoji_info_reply(locale, &get_string_option(&data.options, "emoji"), user)
+        }
+        "b64encode" => {
+            ciphers::get_b64_encode_reply(&get_string_option(&data.options, "message"), locale)
+        }
+        "b64decode" => {
+            ciphers::get_b64_decode_reply(&get_string_option(&data.options, "message"), locale)
+        }
+        "gif" => {
+            let nsfw = match interaction.channel_id.to_channel(ctx).await {
+                Ok(channel) => channel.is_nsfw(),
+                Err(_) => false,
+            };
+
+            images::get_gif_reply(
+                ctx,
+                locale,
+                &get_string_option(&data.options, "search"),
+                nsfw,
+            )
+            .await?
+        }
+        "hug" | "pat" | "slap" | "kiss" => match interaction.guild_id {
+            // Leaving out the user gives the reaction to everyone, like `everyone` on text commands
+            Some(guild_id) => {
+                let target = get_user_option(&data.options, "user");
+
+                images::get_targeted_reply(ctx, locale, guild_id, &data.name, user, target.as_ref())
+                    .await?
+            }
+            None => CommandReply::Text(locale.get("interactions.guild_only")),
+        },
+        "disgust" | "cry" | "cringe" => match interaction.guild_id {
+            Some(guild_id) => {
+                images::get_solo_reply(ctx, locale, guild_id, &data.name, user).await?
+            }
+            None => CommandReply::Text(locale.get("interactions.guild_only")),
+        },
+        "anime" => {
+            japan::get_top_anime_reply(ctx, locale, &get_string_option(&data.options, "title"))
+                .await?
+        }
+        "manga" => {
+            japan::get_top_manga_reply(ctx, locale, &get_string_option(&data.options, "title"))
+                .await?
+        }
+        "starboard" => match interaction.guild_id {
+            Some(guild_id) => handle_starboard(ctx, interaction, guild_id, data, locale).await?,
+            None => CommandReply::Text(locale.get("interactions.guild_only")),
+        },
+        "summon" | "disconnect" | "play" | "pause" | "resume" | "stop" | "skip" | "queue"
+        | "remove" | "seek" | "clear" => {
+            let guild_id = match interaction.guild_id {
+                Some(guild_id) => guild_id,
+                None => return Ok(CommandReply::Text(locale.get("interactions.guild_only"))),
+            };
+
+            // The text command has a DJ check, which the framework doesn't run for slash commands
+            if data.name == "clear"
+                && !has_interaction_level(ctx, interaction, guild_id, PermissionLevel::Dj).await
+            {
+                return Ok(CommandReply::Text(
+                    PermissionType::Level(PermissionLevel::Dj).localize(locale),
+                ));
+            }
+
+            // Voice states come from the cache, so music can't run until the guild is cached
+            match guild_id.to_guild_cached(ctx).await {
+                Some(guild) => handle_music(ctx, &guild, user, data, locale).await?,
+                None => CommandReply::Text(locale.get("interactions.guild_unavailable")),
+            }
+        }
+        name => match get_textmod_string(name, &get_string_option(&data.options, "message")) {
+            Some(modified) => CommandReply::Text(modified),
+            None => CommandReply::Text(locale.get("interactions.unknown_command")),
+        },
+    };
+
+    Ok(reply)
+}
+
+// Flattens the invoked options, ex. `threshold board: main threshold: 5`
+fn get_interaction_args(options: &[ApplicationCommandInteractionDataOption]) -> String {
+    options
+        .iter()
+        .map(|option| match &option.value {
+            Some(value) => match value.as_str() {
+                Some(value) => format!("{}: {}", option.name, value),
+                None => format!("{}: {}", option.name, value),
+            },
+            None => format!("{} {}", option.name, get_interaction_args(&option.options))
+                .trim_end()
+                .to_owned(),
+        })
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+fn get_textmod_string(name: &str, input: &str) -> Option<String> {
+    let modified = match name {
+        "mock" => textmod_helper::get_mock_string(input),
+        "inv" => textmod_helper::get_inverted_string(input),
+        "upp" => input.to_uppercase(),
+        "low" => input.to_lowercase(),
+        "space" => textmod_helper::get_spaced_string(input, false),
+        "biggspace" => textmod_helper::get_spaced_string(input, true),
+        "h4ck" => textmod_helper::get_hacked_string(input),
+        "uwu" => textmod_helper::get_uwu_string(input),
+        _ => return None,
+    };
+
+    Some(modified)
+}
+
+async fn handle_starboard(
+    ctx: &Context,
+    interaction: &Interaction,
+    guild_id: GuildId,
+    data: &ApplicationCommandInteractionData,
+    locale: &Locale,
+) -> CommandResult<CommandReply> {
+    if !has_interaction_level(ctx, interaction, guild_id, PermissionLevel::Moderator).await {
+        return Ok(CommandReply::Text(
+            PermissionType::Level(PermissionLevel::Moderator).localize(locale),
+        ));
+    }
+
+    let subcommand = match data.options.first() {
+        Some(subcommand) => subcommand,
+        None => {
+            return Ok(CommandReply::Text(
+                locale.get("interactions.missing_subcommand"),
+            ))
+        }
+    };
+
+    let name = get_string_option(&subcommand.options, "board").to_lowercase();
+
+    let changes = match subcommand.name.as_str() {
+        "channel" => match get_channel_option(&subcommand.options, "channel") {
+            Some(channel_id) => BoardChanges {
+                channel: Some(channel_id),
+                ..Default::default()
+            },
+            None => return Ok(CommandReply::Text(locale.get("starboard.missing_channel"))),
+        },
+        "threshold" => {
+            let threshold = get_integer_option(&subcommand.options, "threshold").unwrap_or(0);
+
+            // Out of range values become 0, which is rejected with the other invalid thresholds
+            BoardChanges {
+                threshold: Some(u32::try_from(threshold).unwrap_or(0)),
+                ..Default::default()
+            }
+        }
+        _ => {
+            return Ok(CommandReply::Text(
+                locale.get("interactions.missing_subcommand"),
+            ))
+        }
+    };
+
+    starboard::edit_board_internal(ctx, locale, guild_id, &name, changes).await
+}
+
+async fn has_interaction_level(
+    ctx: &Context,
+    interaction: &Interaction,
+    guild_id: GuildId,
+    level: PermissionLevel,
+) -> bool {
+    match &interaction.member {
+        Some(member) => {
+            permissions_helper::has_permission_level(
+                ctx,
+                guild_id,
+                &member.roles,
+                member.permissions.unwrap_or_else(Permissions::empty),
+                level,
+            )
+            .await
+        }
+        None => false,
+    }
+}
+
+async fn handle_music(
+    ctx: &Context,
+    guild: &Guild,
+    author: &User,
+    data: &ApplicationCommandInteractionData,
+    locale: &Locale,
+) -> CommandResult<CommandReply> {
+    match data.name.as_str() {
+        "summon" => voice_utils::summon_internal(ctx, guild, author.id).await,
+        "disconnect" => voice_utils::disconnect_internal(ctx, guild, author.id).await,
+        "play" => {
+            let track = get_string_option(&data.options, "track");
+
+            music::play_internal(ctx, guild, author, &track).await
+        }
+        "pause" => music::pause_internal(ctx, guild, author.id).await,
+        "resume" => music::resume_internal(ctx, guild, author.id).await,
+        "stop" => music::stop_internal(ctx, guild, author.id).await,
+        "skip" => music::skip_internal(ctx, guild, author.id).await,
+        "queue" => music::queue_internal(ctx, guild, author.id).await,
+        "remove" => match get_integer_option(&data.options, "position") {
+            Some(position) if position > 0 => {
+                music::remove_internal(ctx, guild, author.id, position as usize).await
+            }
+            _ => Ok(CommandReply::Text(
+                JesterError::MissingError("number greater than 0").localize(locale),
+            )),
+        },
+        "seek" => {
+            let time = get_string_option(&data.options, "time");
+
+            music::seek_internal(ctx, guild, author.id, Some(time)).await
+        }
+        "clear" => music::clear_internal(ctx, guild, author.id).await,
+        _ => Ok(CommandReply::Text(locale.get("interactions.unknown_music"))),
+    }
+}
+
+fn get_option<'a>(
+    options: &'a [ApplicationCommandInteractionDataOption],
+    name: &str,
+) -> Option<&'a ApplicationCommandInteractionDataOption> {
+    options.iter().find(|option| option.name == name)
+}
+
+fn get_string_option(options: &[ApplicationCommandInteractionDataOption], name: &str) -> String {
+    get_option(options, name)
+        .and_then(|option| option.value.as_ref())
+        .and_then(|value| value.as_str())
+        .unwrap_or_default()
+        .to_owned()
+}
+
+fn get_integer_option(
+    options: &[ApplicationCommandInteractionDataOption],
+    name: &str,
+) -> Option<i64> {
+    match get_option(options, name)?.resolved.as_ref()? {
+        ApplicationCommandInteractionDataOptionValue::Integer(value) => Some(*value),
+        _ => None,
+    }
+}
+
+fn get_channel_option(
+    options: &[ApplicationCommandInteractionDataOption],
+    name: &str,
+) -> Option<ChannelId> {
+    match get_option(options, name)?.resolved.as_ref()? {
+        ApplicationCommandInteractionDataOptionValue::Channel(channel) => Some(channel.id),
+        _ => None,
+    }
+}
+
+fn get_user_option(
+    options: &[ApplicationCommandInteractionDataOption],
+    name: &str,
+) -> Option<User> {
+    match get_option(options, name)?.resolved.as_ref()? {
+        ApplicationCommandInteractionDataOptionValue::User(user, _) => Some(user.clone()),
+        _ => None,
+    }
+}
+
+/*
+ * Reactions can't be added to an interaction, so they are sent as message content instead
+ * Deferred interactions already have a response, which is edited with the reply
+ */
+async fn send_interaction_reply(
+    ctx: &Context,
+    interaction: &Interaction,
+    reply: CommandReply,
+    deferred: bool,
+) -> CommandResult {
+    let (content, embed): (Option<String>, Option<CreateEmbed>) = match reply {
+        CommandReply::Text(content) => (Some(content), None),
+        CommandReply::Embed(eb) => (None, Some(eb)),
+        CommandReply::Reaction(reaction) => (Some(reaction.to_string()), None),
+    };
+
+    if deferred {
+        interaction
+            .edit_original_interaction_response(&ctx.http, |r| {
+                if let Some(content) = content {
+                    r.content(content);
+                }
+
+                if let Some(eb) = embed {
+                    r.embeds(vec![eb]);
+                }
+
+                r
+            })
+            .await?;
+
+        return Ok(());
+    }
+
+    interaction
+        .create_interaction_response(&ctx.http, |r| {
+            r.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| {
+                    if let Some(content) = content {
+                        d.content(content);
+                    }
+
+                    if let Some(eb) = embed {
+                        d.embeds(vec![eb]);
+                    }
+
+                    d
+                })
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,2 @@
+pub mod interaction_handler;
+pub mod registry;
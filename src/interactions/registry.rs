@@ -0,0 +1,267 @@
+use serenity::{
+    client::Context,
+    framework::standard::CommandResult,
+    model::interactions::{ApplicationCommand, ApplicationCommandOptionType},
+};
+
+// Reaction gifs that are given to another user, or to everyone when no user is picked
+pub const TARGETED_IMAGE_COMMANDS: [(&str, &str); 4] = [
+    ("hug", "Gives wholesome hugs to someone"),
+    ("pat", "Pats someone on the head"),
+    ("slap", "Gives someone a slap"),
+    ("kiss", "Kisses someone"),
+];
+
+// Reaction gifs about the user running the command
+pub const SOLO_IMAGE_COMMANDS: [(&str, &str); 3] = [
+    ("disgust", "Emphasizes that you're disgusted"),
+    ("cry", "Emphasizes that you're crying"),
+    ("cringe", "Emphasizes that something is cringey"),
+];
+
+// Text modification commands that take a single string to modify
+pub const TEXTMOD_COMMANDS: [(&str, &str); 8] = [
+    ("mock", "Outputs a spongebob mock string"),
+    ("inv", "Inverts the case of every character"),
+    ("upp", "Makes every character uppercase"),
+    ("low", "Makes every character lowercase"),
+    (
+        "space",
+        "Puts a random amount of spaces between each character",
+    ),
+    (
+        "biggspace",
+        "Puts a large amount of spaces between each character",
+    ),
+    ("h4ck", "Makes your message look like a hacker wrote it"),
+    ("uwu", "Uwuifies your message"),
+];
+
+/*
+ * Registers the global application commands for the bot
+ * Option names match the arguments of the text command each slash command mirrors
+ *
+ * Left out on purpose:
+ * - Config and the owner commands, which rely on nested subcommands and message wizards
+ * - kang and spoiler, which need emoji uploads and attachments that options can't carry
+ * - nice, bruh, quote and vibecheck, which post into configured channels or quote messages
+ * - The textmods ending in `l`, which modify the message sent before the command
+ */
+pub async fn register_slash_commands(ctx: &Context) -> CommandResult {
+    ApplicationCommand::create_global_application_commands(&ctx.http, |commands| {
+        commands.create_application_command(|c| c.name("ping").description("Pong!"));
+        commands.create_application_command(|c| {
+            c.name("support")
+                .description("Links to get help with the bot")
+        });
+        commands.create_application_command(|c| {
+            c.name("help")
+                .description("Shows every command group, or the details of a group or command")
+                .create_option(|o| {
+                    o.name("query")
+                        .description("A group or command, ex. `config cooldown set`")
+                        .kind(ApplicationCommandOptionType::String)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("info")
+                .description("Shows information about the bot")
+        });
+        commands.create_application_command(|c| {
+            c.name("avatar")
+                .description("Gets the avatar of yourself or another user")
+                .create_option(|o| {
+                    o.name("user")
+                        .description("The user to get the avatar of")
+                        .kind(ApplicationCommandOptionType::User)
+                })
+        });
+
+        commands.create_application_command(|c| {
+            c.name("emoji_info")
+                .description("Gets the information of an emoji")
+                .create_option(|o| {
+                    o.name("emoji")
+                        .description("A custom emoji")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+
+        for (name, description) in TEXTMOD_COMMANDS.iter() {
+            commands.create_application_command(|c| {
+                c.name(name).description(description).create_option(|o| {
+                    o.name("message")
+                        .description("The message to modify")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+            });
+        }
+
+        commands.create_application_command(|c| {
+            c.name("b64encode")
+                .description("Encodes a message in base64")
+                .create_option(|o| {
+                    o.name("message")
+                        .description("The message to encode")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("b64decode")
+                .description("Decodes a message in base64")
+                .create_option(|o| {
+                    o.name("message")
+                        .description("The base64 string to decode")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("gif")
+                .description("Fetches a random gif from tenor")
+                .create_option(|o| {
+                    o.name("search")
+                        .description("What to search for")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+
+        for (name, description) in TARGETED_IMAGE_COMMANDS.iter() {
+            commands.create_application_command(|c| {
+                c.name(name).description(description).create_option(|o| {
+                    o.name("user")
+                        .description("Who to send it to. Leave empty for everyone")
+                        .kind(ApplicationCommandOptionType::User)
+                })
+            });
+        }
+
+        for (name, description) in SOLO_IMAGE_COMMANDS.iter() {
+            commands.create_application_command(|c| c.name(name).description(description));
+        }
+
+        commands.create_application_command(|c| {
+            c.name("anime")
+                .description("Searches for an anime's information from the title")
+                .create_option(|o| {
+                    o.name("title")
+                        .description("The title of the anime")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("manga")
+                .description("Searches for a manga's information from the title")
+                .create_option(|o| {
+                    o.name("title")
+                        .description("The title of the manga")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("starboard")
+                .description("Configures the server's starboards")
+                .create_option(|o| {
+                    o.name("channel")
+                        .description("Sets a board's channel")
+                        .kind(ApplicationCommandOptionType::SubCommand)
+                        .create_sub_option(|s| {
+                            s.name("board")
+                                .description("The name of the board")
+                                .kind(ApplicationCommandOptionType::String)
+                                .required(true)
+                        })
+                        .create_sub_option(|s| {
+                            s.name("channel")
+                                .description("The new board channel")
+                                .kind(ApplicationCommandOptionType::Channel)
+                                .required(true)
+                        })
+                })
+                .create_option(|o| {
+                    o.name("threshold")
+                        .description(
+                            "Sets the amount of reactions a board needs to quote a message",
+                        )
+                        .kind(ApplicationCommandOptionType::SubCommand)
+                        .create_sub_option(|s| {
+                            s.name("board")
+                                .description("The name of the board")
+                                .kind(ApplicationCommandOptionType::String)
+                                .required(true)
+                        })
+                        .create_sub_option(|s| {
+                            s.name("threshold")
+                                .description("The new threshold")
+                                .kind(ApplicationCommandOptionType::Integer)
+                                .required(true)
+                        })
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("summon")
+                .description("Forces the bot to join your voice chat")
+        });
+        commands.create_application_command(|c| {
+            c.name("disconnect")
+                .description("Leaves the voice chat and clears everything")
+        });
+        commands.create_application_command(|c| {
+            c.name("play")
+                .description("Plays a track or adds it to the queue")
+                .create_option(|o| {
+                    o.name("track")
+                        .description("A URL or search query")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("pause").description("Pauses the current track")
+        });
+        commands.create_application_command(|c| {
+            c.name("resume").description("Resumes the current track")
+        });
+        commands.create_application_command(|c| {
+            c.name("stop")
+                .description("Stops playback and clears the queue")
+        });
+        commands
+            .create_application_command(|c| c.name("skip").description("Skips the current track"));
+        commands
+            .create_application_command(|c| c.name("queue").description("Shows the current queue"));
+        commands.create_application_command(|c| {
+            c.name("remove")
+                .description("Removes a track from the queue")
+                .create_option(|o| {
+                    o.name("position")
+                        .description("The position of the track in the queue")
+                        .kind(ApplicationCommandOptionType::Integer)
+                        .required(true)
+                })
+        });
+        commands.create_application_command(|c| {
+            c.name("seek")
+                .description("Seeks in the current track")
+                .create_option(|o| {
+                    o.name("time")
+                        .description("The time to seek to in hh:mm:ss or mm:ss format")
+                        .kind(ApplicationCommandOptionType::String)
+                        .required(true)
+                })
+        });
+        commands
+            .create_application_command(|c| c.name("clear").description("Clears the entire queue"));
+
+        commands
+    })
+    .await?;
+
+    Ok(())
+}
@@ -1,6 +1,7 @@
 mod commands;
 mod handlers;
 mod helpers;
+mod interactions;
 mod reactions;
 mod structures;
 
@@ -9,7 +10,7 @@ use crate::{
         event_handler::{LavalinkHandler, SerenityHandler},
         framework::get_framework,
     },
-    helpers::{command_utils, database_helper},
+    helpers::{command_utils, database_helper, locale_helper},
     structures::{cmd_data::*, commands::*, errors::*},
 };
 use aspotify::{Client as Spotify, ClientCredentials};
@@ -18,8 +19,11 @@ use futures::future::AbortHandle;
 use lavalink_rs::LavalinkClient;
 use reqwest::Client as Reqwest;
 use serenity::{
-    client::bridge::gateway::GatewayIntents, framework::standard::CommandResult, http::Http,
-    model::id::GuildId, prelude::*,
+    client::bridge::gateway::GatewayIntents,
+    framework::{standard::CommandResult, Framework},
+    http::Http,
+    model::id::GuildId,
+    prelude::*,
 };
 use songbird::SerenityInit;
 use std::{
@@ -50,6 +54,19 @@ async fn main() -> CommandResult {
 
     let pool = database_helper::obtain_db_pool(creds.db_connection).await?;
     let prefixes = database_helper::fetch_prefixes(&pool).await?;
+    let command_rules = database_helper::fetch_command_rules(&pool).await?;
+    let cooldowns = database_helper::fetch_cooldowns(&pool).await?;
+    let languages = database_helper::fetch_languages(&pool).await?;
+    let aliases = database_helper::fetch_aliases(&pool).await?;
+    let permission_roles = database_helper::fetch_permission_roles(&pool).await?;
+    let blocklist = database_helper::fetch_blocklist(&pool).await?;
+    let mention_commands = database_helper::fetch_mention_commands(&pool).await?;
+    let autoresponders = database_helper::fetch_autoresponders(&pool).await?;
+    let custom_commands = database_helper::fetch_custom_commands(&pool).await?;
+    let suggestion_opt_outs = database_helper::fetch_suggestion_opt_outs(&pool).await?;
+    let starboards = database_helper::fetch_starboards(&pool).await?;
+    let starboard_filters = database_helper::fetch_starboard_filters(&pool).await?;
+    let locales = locale_helper::load_locales("locales")?;
     let voice_timer_map: DashMap<GuildId, AbortHandle> = DashMap::new();
 
     let lava_client = LavalinkClient::builder(bot_id)
@@ -71,31 +88,26 @@ async fn main() -> CommandResult {
 
     let emergency_commands = command_utils::get_allowed_commands();
 
-    let command_names = MASTER_GROUP
-        .options
-        .sub_groups
-        .iter()
-        .flat_map(|x| {
-            x.options
-                .commands
-                .iter()
-                .flat_map(|i| i.options.names.iter().map(ToString::to_string))
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<String>>();
+    let command_names = command_utils::get_command_names(MASTER_GROUP.options.sub_groups);
+
+    let command_groups = command_utils::get_command_group_map(MASTER_GROUP.options.sub_groups);
 
     let reqwest_client = Reqwest::builder()
         .user_agent("Mozilla/5.0 (X11; Linux x86_64; rv:73.0) Gecko/20100101 Firefox/73.0")
         .build()?;
 
+    // The framework is shared so guild aliases can dispatch the command they point to
+    let framework: Arc<Box<dyn Framework + Send + Sync>> =
+        Arc::new(Box::new(get_framework(bot_id, owners).await));
+
     let mut client = Client::builder(&token)
-        .framework(get_framework(bot_id, owners))
+        .application_id(bot_id.0)
+        .framework_arc(framework.clone())
         .event_handler(SerenityHandler {
             run_loop: AtomicBool::new(true),
         })
         .intents({
             let mut intents = GatewayIntents::all();
-            intents.remove(GatewayIntents::DIRECT_MESSAGES);
             intents.remove(GatewayIntents::DIRECT_MESSAGE_REACTIONS);
             intents.remove(GatewayIntents::DIRECT_MESSAGE_TYPING);
             intents
@@ -114,9 +126,29 @@ async fn main() -> CommandResult {
         data.insert::<VoiceTimerMap>(Arc::new(voice_timer_map));
         data.insert::<PrefixMap>(Arc::new(prefixes));
         data.insert::<CommandNameMap>(Arc::new(command_names));
+        data.insert::<CommandRuleMap>(Arc::new(command_rules));
+        data.insert::<CommandGroupMap>(Arc::new(command_groups));
+        data.insert::<CooldownMap>(Arc::new(cooldowns));
+        data.insert::<CooldownTracker>(Arc::new(DashMap::new()));
+        data.insert::<SuggestionTracker>(Arc::new(DashMap::new()));
+        data.insert::<Locales>(Arc::new(locales));
+        data.insert::<GuildLanguageMap>(Arc::new(languages));
+        data.insert::<AliasMap>(Arc::new(aliases));
+        data.insert::<PermissionRoleMap>(Arc::new(permission_roles));
+        data.insert::<Blocklist>(Arc::new(blocklist));
+        data.insert::<FrameworkContainer>(framework);
+        data.insert::<CommandTimers>(Arc::new(DashMap::new()));
+        data.insert::<UsageBuffer>(Arc::new(Mutex::new(Vec::new())));
         data.insert::<ReqwestClient>(reqwest_client);
         data.insert::<PubCreds>(Arc::new(pub_creds));
         data.insert::<EmergencyCommands>(Arc::new(emergency_commands));
+        data.insert::<MentionCommandMap>(Arc::new(mention_commands));
+        data.insert::<AutoResponderMap>(Arc::new(autoresponders));
+        data.insert::<CustomCommandMap>(Arc::new(custom_commands));
+        data.insert::<SuggestionOptOuts>(Arc::new(suggestion_opt_outs));
+        data.insert::<StarboardMap>(Arc::new(starboards));
+        data.insert::<StarboardFilterMap>(Arc::new(starboard_filters));
+        data.insert::<StarReactorCache>(Arc::new(DashMap::new()));
         data.insert::<BotId>(bot_id);
         data.insert::<SpotifyClient>(Arc::new(spotify));
         data.insert::<ReactionImageCache>(Arc::new(DashMap::new()));
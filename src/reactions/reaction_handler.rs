@@ -1,10 +1,28 @@
 use serenity::{client::Context, framework::standard::CommandResult, model::channel::Reaction};
 
-use crate::reactions::starboard;
+use crate::{reactions::starboard, StarboardMap};
 
 pub async fn dispatch_reaction(ctx: &Context, reaction: &Reaction, remove: bool) -> CommandResult {
-    if reaction.emoji.as_data() == "⭐" {
-        starboard::quote_reaction(ctx, reaction, remove).await?;
+    if let Some(guild_id) = reaction.guild_id {
+        let starboard_map = ctx
+            .data
+            .read()
+            .await
+            .get::<StarboardMap>()
+            .cloned()
+            .unwrap();
+
+        // Clone the board so the cache isn't locked while the starboard message is sent
+        let board = starboard_map.get(&guild_id).and_then(|boards| {
+            boards
+                .iter()
+                .find(|board| board.uses_emoji(&reaction.emoji))
+                .cloned()
+        });
+
+        if let Some(board) = board {
+            starboard::quote_reaction(ctx, reaction, &board, remove).await?;
+        }
     }
 
     Ok(())
@@ -1,67 +1,93 @@
-use std::time::{SystemTime, UNIX_EPOCH};
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
 
 use serenity::{
     builder::CreateEmbed,
     client::Context,
     framework::standard::CommandResult,
     model::{
-        channel::{Attachment, Reaction},
-        id::{ChannelId, GuildId, MessageId},
+        channel::{Attachment, Channel, Reaction, ReactionType},
+        id::{GuildId, MessageId, UserId},
         prelude::User,
     },
     prelude::Mentionable,
 };
 use sqlx::PgPool;
 
-use crate::{helpers::command_utils, structures::cmd_data::ConnectionPool};
+use crate::{
+    helpers::{command_utils, locale_helper},
+    structures::{
+        cmd_data::{ConnectionPool, StarReactorCache, StarboardFilterMap},
+        Locale, StarReactors, Starboard, StarboardFilters,
+    },
+};
 
-struct StarbotConfig {
-    starboard_threshold: Option<i32>,
-    quote_id: Option<i64>,
-}
+// Reacting users are refetched after this in case reaction events were missed
+const REACTOR_CACHE_TIME: Duration = Duration::from_secs(600);
 
-pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) -> CommandResult {
-    let pool = ctx
-        .data
-        .read()
-        .await
-        .get::<ConnectionPool>()
-        .cloned()
-        .unwrap();
-    let reaction_message = reaction.message(ctx).await?;
+// Discord returns up to 100 reacting users per request
+const MAX_REACTOR_PAGES: usize = 10;
 
-    let reaction_channel = reaction.channel(ctx).await?;
+pub async fn quote_reaction(
+    ctx: &Context,
+    reaction: &Reaction,
+    board: &Starboard,
+    remove: bool,
+) -> CommandResult {
+    let (pool, starboard_filters) = {
+        let data = ctx.data.read().await;
+        let pool = data.get::<ConnectionPool>().cloned().unwrap();
+        let starboard_filters = data.get::<StarboardFilterMap>().cloned().unwrap();
 
-    let reactions = reaction_message.reactions;
-    let stars = match reactions
-        .into_iter()
-        .find(|x| x.reaction_type.as_data() == "⭐")
-    {
-        Some(reaction) => reaction.count,
-        None => 0,
+        (pool, starboard_filters)
     };
 
-    let config_data = sqlx::query_as!(StarbotConfig, "SELECT guild_info.starboard_threshold, text_channels.quote_id
-                                    FROM guild_info
-                                    INNER JOIN text_channels ON guild_info.guild_id=text_channels.guild_id
-                                    WHERE guild_info.guild_id = $1", reaction.guild_id.unwrap().0 as i64)
-        .fetch_one(&pool).await?;
+    let filters = starboard_filters
+        .get(&reaction.guild_id.unwrap())
+        .map(|filters| *filters)
+        .unwrap_or_default();
 
-    if config_data.starboard_threshold.is_none() || config_data.quote_id.is_none() {
+    let reaction_message = reaction.message(ctx).await?;
+
+    if filters.ignore_bots && reaction_message.author.bot {
         return Ok(());
     }
 
+    let reaction_channel = reaction.channel(ctx).await?;
+
+    let stars = if filters.is_empty() {
+        let reactions = reaction_message.reactions;
+        match reactions
+            .into_iter()
+            .find(|x| board.uses_emoji(&x.reaction_type))
+        {
+            Some(reaction) => reaction.count,
+            None => 0,
+        }
+    } else {
+        count_eligible_stars(
+            ctx,
+            reaction,
+            board,
+            reaction_message.author.id,
+            filters,
+            remove,
+        )
+        .await?
+    };
+
     // Add timer calls
 
-    let star_channel_id = ChannelId(config_data.quote_id.unwrap() as u64);
+    let locale = locale_helper::get_locale(ctx, reaction.guild_id).await;
+
+    let star_channel_id = board.channel_id;
     let star_channel = match ctx.cache.channel(star_channel_id).await {
         Some(star_channel) => star_channel,
         None => {
             star_channel_id
-                .say(
-                    ctx,
-                    "The star channel can't be found! Please set a new one!",
-                )
+                .say(ctx, locale.get("starboard.channel_missing"))
                 .await?;
             return Ok(());
         }
@@ -70,23 +96,17 @@ pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) ->
     if !star_channel.is_nsfw() && reaction.channel(ctx).await?.is_nsfw() {
         reaction_channel
             .id()
-            .say(
-                ctx,
-                "You can't star an NSFW message in a non-nsfw starboard!",
-            )
+            .say(ctx, locale.get("starboard.nsfw"))
             .await?;
         return Ok(());
     }
 
-    if stars == config_data.starboard_threshold.unwrap() as u64 && !remove {
-        let first_message = format!(
-            "\u{2b50} {} {} ID: {}",
-            stars,
-            reaction_channel.mention(),
-            reaction.message_id
-        );
+    if stars == board.threshold as u64 && !remove {
+        let first_message =
+            get_starboard_header(&locale, &board.emoji, stars, &reaction_channel, reaction);
 
         let starboard_embed = get_starboard_embed(
+            &locale,
             reaction,
             &reaction_message.author,
             reaction_message.content,
@@ -94,7 +114,7 @@ pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) ->
         );
 
         let guild_id = reaction.guild_id.unwrap();
-        if !check_starboard_message(&pool, guild_id, reaction_message.id).await? {
+        if !check_starboard_message(&pool, guild_id, &board.name, reaction_message.id).await? {
             let sent_message = star_channel_id
                 .send_message(ctx, |m| {
                     m.content(first_message);
@@ -112,8 +132,10 @@ pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) ->
                 + 1210000;
 
             sqlx::query!(
-                "INSERT INTO starboard VALUES($1, $2, $3, $4) ON CONFLICT DO NOTHING",
+                "INSERT INTO starboard_messages(guild_id, board_name, reaction_message_id, sent_message_id, delete_time)
+                    VALUES($1, $2, $3, $4, $5) ON CONFLICT DO NOTHING",
                 reaction.guild_id.unwrap().0 as i64,
+                board.name,
                 reaction_message.id.0 as i64,
                 sent_message.id.0 as i64,
                 advance_time as i64
@@ -121,9 +143,9 @@ pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) ->
             .execute(&pool)
             .await?;
         }
-    } else if (stars as i32) < config_data.starboard_threshold.unwrap() && remove {
-        let message_data = sqlx::query!("SELECT sent_message_id FROM starboard WHERE guild_id = $1 AND reaction_message_id = $2", 
-                reaction.guild_id.unwrap().0 as i64, reaction.message_id.0 as i64)
+    } else if stars < board.threshold as u64 && remove {
+        let message_data = sqlx::query!("SELECT sent_message_id FROM starboard_messages WHERE guild_id = $1 AND board_name = $2 AND reaction_message_id = $3", 
+                reaction.guild_id.unwrap().0 as i64, board.name, reaction.message_id.0 as i64)
             .fetch_optional(&pool).await?;
 
         if let Some(data) = message_data {
@@ -132,26 +154,24 @@ pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) ->
                 .await?;
 
             sqlx::query!(
-                "DELETE FROM starboard WHERE guild_id = $1 and reaction_message_id = $2",
+                "DELETE FROM starboard_messages WHERE guild_id = $1 AND board_name = $2 AND reaction_message_id = $3",
                 reaction.guild_id.unwrap().0 as i64,
+                board.name,
                 reaction.message_id.0 as i64
             )
             .execute(&pool)
             .await?;
         }
-    } else if stars > config_data.starboard_threshold.unwrap() as u64 || remove {
-        let message_data = sqlx::query!("SELECT sent_message_id FROM starboard WHERE guild_id = $1 AND reaction_message_id = $2", 
-                reaction.guild_id.unwrap().0 as i64, reaction.message_id.0 as i64)
+    } else if stars > board.threshold as u64 || remove {
+        let message_data = sqlx::query!("SELECT sent_message_id FROM starboard_messages WHERE guild_id = $1 AND board_name = $2 AND reaction_message_id = $3", 
+                reaction.guild_id.unwrap().0 as i64, board.name, reaction.message_id.0 as i64)
             .fetch_optional(&pool).await?;
 
         if let Some(data) = message_data {
-            let first_message = format!(
-                "\u{2b50} {} {} ID: {}",
-                stars,
-                reaction_channel.mention(),
-                reaction.message_id
-            );
+            let first_message =
+                get_starboard_header(&locale, &board.emoji, stars, &reaction_channel, reaction);
             let eb = get_starboard_embed(
+                &locale,
                 reaction,
                 &reaction_message.author,
                 reaction_message.content,
@@ -177,14 +197,116 @@ pub async fn quote_reaction(ctx: &Context, reaction: &Reaction, remove: bool) ->
     Ok(())
 }
 
+// Filtered counts need the reacting users. They're cached so each reaction doesn't refetch them
+async fn count_eligible_stars(
+    ctx: &Context,
+    reaction: &Reaction,
+    board: &Starboard,
+    author_id: UserId,
+    filters: StarboardFilters,
+    remove: bool,
+) -> CommandResult<u64> {
+    let reactor_cache = ctx
+        .data
+        .read()
+        .await
+        .get::<StarReactorCache>()
+        .cloned()
+        .unwrap();
+
+    let key = (reaction.message_id, board.name.to_owned());
+
+    let is_fresh = reactor_cache.get(&key).map_or(false, |reactors| {
+        reactors.fetched.elapsed() < REACTOR_CACHE_TIME
+    });
+
+    match reaction.user_id {
+        Some(user_id) if is_fresh => {
+            // Look the user up before locking the entry, since that can make a request
+            let is_bot = if remove {
+                false
+            } else {
+                user_id.to_user(ctx).await?.bot
+            };
+
+            if let Some(mut reactors) = reactor_cache.get_mut(&key) {
+                if remove {
+                    reactors.users.remove(&user_id);
+                } else {
+                    reactors.users.insert(user_id, is_bot);
+                }
+            }
+        }
+        _ => {
+            let users = fetch_reactors(ctx, reaction).await?;
+
+            // Drop expired messages while the cache is being written to anyways
+            reactor_cache.retain(|_, reactors| reactors.fetched.elapsed() < REACTOR_CACHE_TIME);
+            reactor_cache.insert(
+                key.clone(),
+                StarReactors {
+                    users,
+                    fetched: Instant::now(),
+                },
+            );
+        }
+    }
+
+    let stars = reactor_cache.get(&key).map_or(0, |reactors| {
+        reactors
+            .users
+            .iter()
+            .filter(|(user_id, is_bot)| {
+                !(filters.ignore_self && **user_id == author_id)
+                    && !(filters.ignore_bot_stars && **is_bot)
+            })
+            .count()
+    });
+
+    Ok(stars as u64)
+}
+
+async fn fetch_reactors(
+    ctx: &Context,
+    reaction: &Reaction,
+) -> CommandResult<HashMap<UserId, bool>> {
+    let mut users = HashMap::new();
+    let mut after = None;
+
+    for _ in 0..MAX_REACTOR_PAGES {
+        let page = reaction
+            .channel_id
+            .reaction_users(
+                ctx,
+                reaction.message_id,
+                reaction.emoji.clone(),
+                Some(100),
+                after,
+            )
+            .await?;
+
+        let is_last = page.len() < 100;
+        after = page.last().map(|user| user.id);
+        users.extend(page.into_iter().map(|user| (user.id, user.bot)));
+
+        if is_last {
+            break;
+        }
+    }
+
+    Ok(users)
+}
+
 async fn check_starboard_message(
     pool: &PgPool,
     guild_id: GuildId,
+    board_name: &str,
     reaction_msg_id: MessageId,
 ) -> CommandResult<bool> {
     let message_query = sqlx::query!(
-        "SELECT * FROM starboard WHERE guild_id=$1 AND reaction_message_id=$2",
+        "SELECT * FROM starboard_messages WHERE guild_id=$1 AND board_name=$2 AND reaction_message_id=$3",
         guild_id.0 as i64,
+        board_name,
         reaction_msg_id.0 as i64
     )
     .fetch_optional(pool)
@@ -193,7 +315,26 @@ async fn check_starboard_message(
     Ok(message_query.is_some())
 }
 
+fn get_starboard_header(
+    locale: &Locale,
+    star_emoji: &ReactionType,
+    stars: u64,
+    reaction_channel: &Channel,
+    reaction: &Reaction,
+) -> String {
+    locale.format(
+        "starboard.header",
+        &[
+            ("emoji", star_emoji),
+            ("stars", &stars),
+            ("channel", &reaction_channel.mention()),
+            ("id", &reaction.message_id),
+        ],
+    )
+}
+
 fn get_starboard_embed(
+    locale: &Locale,
     reaction: &Reaction,
     user: &User,
     content: String,
@@ -222,7 +363,11 @@ fn get_starboard_embed(
         reaction.channel_id,
         reaction.message_id,
     );
-    eb.field("Source", format!("[Jump!]({})", message_url), false);
+    eb.field(
+        locale.get("source"),
+        locale.format("jump", &[("url", &message_url)]),
+        false,
+    );
 
     eb
 }
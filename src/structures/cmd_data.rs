@@ -1,15 +1,22 @@
 use aspotify::Client as Spotify;
-use dashmap::DashMap;
+use dashmap::{DashMap, DashSet};
 use futures::future::AbortHandle;
 use lavalink_rs::LavalinkClient;
 use reqwest::Client as Reqwest;
 use serenity::{
     client::bridge::gateway::ShardManager,
-    model::id::{GuildId, UserId},
+    framework::Framework,
+    model::id::{ChannelId, GuildId, MessageId, RoleId, UserId},
     prelude::{Mutex, TypeMapKey},
 };
 use sqlx::PgPool;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Instant};
+
+use crate::structures::{
+    AutoResponder, BlockTarget, CommandLocation, CommandRule, CommandUsage, CooldownOverride,
+    CustomCommand, LocaleCatalog, PermissionLevel, StarReactors, Starboard, StarboardFilters,
+    TrackedUses,
+};
 
 // All command context data structures
 pub struct ShardManagerContainer;
@@ -63,7 +70,7 @@ impl TypeMapKey for BotId {
 pub struct PrefixMap;
 
 impl TypeMapKey for PrefixMap {
-    type Value = Arc<DashMap<GuildId, String>>;
+    type Value = Arc<DashMap<GuildId, Vec<String>>>;
 }
 
 pub struct EmergencyCommands;
@@ -83,3 +90,123 @@ pub struct ReactionImageCache;
 impl TypeMapKey for ReactionImageCache {
     type Value = Arc<DashMap<(GuildId, String), String>>;
 }
+
+pub struct CommandRuleMap;
+
+impl TypeMapKey for CommandRuleMap {
+    type Value = Arc<DashMap<GuildId, Vec<CommandRule>>>;
+}
+
+pub struct CommandGroupMap;
+
+impl TypeMapKey for CommandGroupMap {
+    type Value = Arc<HashMap<String, CommandLocation>>;
+}
+
+pub struct CooldownMap;
+
+impl TypeMapKey for CooldownMap {
+    type Value = Arc<DashMap<GuildId, HashMap<String, CooldownOverride>>>;
+}
+
+pub struct CooldownTracker;
+
+impl TypeMapKey for CooldownTracker {
+    type Value = Arc<DashMap<(GuildId, String, u64), TrackedUses>>;
+}
+
+pub struct CommandTimers;
+
+impl TypeMapKey for CommandTimers {
+    type Value = Arc<DashMap<MessageId, Instant>>;
+}
+
+pub struct UsageBuffer;
+
+impl TypeMapKey for UsageBuffer {
+    type Value = Arc<Mutex<Vec<CommandUsage>>>;
+}
+
+pub struct Locales;
+
+impl TypeMapKey for Locales {
+    type Value = Arc<LocaleCatalog>;
+}
+
+pub struct GuildLanguageMap;
+
+impl TypeMapKey for GuildLanguageMap {
+    type Value = Arc<DashMap<GuildId, String>>;
+}
+
+pub struct SuggestionTracker;
+
+impl TypeMapKey for SuggestionTracker {
+    type Value = Arc<DashMap<ChannelId, Instant>>;
+}
+
+pub struct AliasMap;
+
+impl TypeMapKey for AliasMap {
+    type Value = Arc<DashMap<GuildId, HashMap<String, String>>>;
+}
+
+pub struct FrameworkContainer;
+
+impl TypeMapKey for FrameworkContainer {
+    type Value = Arc<Box<dyn Framework + Send + Sync>>;
+}
+
+pub struct PermissionRoleMap;
+
+impl TypeMapKey for PermissionRoleMap {
+    type Value = Arc<DashMap<GuildId, HashMap<PermissionLevel, Vec<RoleId>>>>;
+}
+
+pub struct Blocklist;
+
+impl TypeMapKey for Blocklist {
+    type Value = Arc<DashSet<BlockTarget>>;
+}
+
+pub struct MentionCommandMap;
+
+impl TypeMapKey for MentionCommandMap {
+    type Value = Arc<DashMap<GuildId, Vec<String>>>;
+}
+
+pub struct AutoResponderMap;
+
+impl TypeMapKey for AutoResponderMap {
+    type Value = Arc<DashMap<GuildId, Vec<AutoResponder>>>;
+}
+
+pub struct CustomCommandMap;
+
+impl TypeMapKey for CustomCommandMap {
+    type Value = Arc<DashMap<GuildId, HashMap<String, CustomCommand>>>;
+}
+
+pub struct SuggestionOptOuts;
+
+impl TypeMapKey for SuggestionOptOuts {
+    type Value = Arc<DashSet<GuildId>>;
+}
+
+pub struct StarboardMap;
+
+impl TypeMapKey for StarboardMap {
+    type Value = Arc<DashMap<GuildId, Vec<Starboard>>>;
+}
+
+pub struct StarboardFilterMap;
+
+impl TypeMapKey for StarboardFilterMap {
+    type Value = Arc<DashMap<GuildId, StarboardFilters>>;
+}
+
+pub struct StarReactorCache;
+
+impl TypeMapKey for StarReactorCache {
+    type Value = Arc<DashMap<(MessageId, String), StarReactors>>;
+}
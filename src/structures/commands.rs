@@ -2,8 +2,9 @@ use serenity::framework::standard::macros::group;
 
 use crate::{
     commands::{
-        ciphers::*, config::*, images::*, japan::*, music::REMOVE_COMMAND, music::*, other::*,
-        starboard::*, support::*, textchannel_send::*, textmod::*, utility::*,
+        aliases::*, autoresponse::*, blocklist::*, ciphers::*, config::*, error_log::*, images::*,
+        japan::*, music::REMOVE_COMMAND, music::*, other::*, starboard::*, stats::*, support::*,
+        textchannel_send::*, textmod::*, utility::*,
     },
     helpers::voice_utils::*,
 };
@@ -21,7 +22,9 @@ use crate::{
     Starboard,
     Voice,
     Images,
-    Music
+    Music,
+    Japan,
+    Utility
 )]
 pub struct Master;
 
@@ -31,9 +34,7 @@ pub struct Master;
 pub struct General;
 
 #[group("Text Modification")]
-#[description = "Commands than modify text. \n
-Append l in the command to use the last message \n
-Example: `mockl` mocks the last message"]
+#[description = "Commands that modify text. Append l to a command (except h4ck and uwu) to use the last message, ex. `mockl`"]
 #[commands(mock, inv, upp, low, space, biggspace, h4ck, uwu)]
 pub struct Text;
 
@@ -54,12 +55,12 @@ pub struct TextChannelSend;
 
 #[group("Bot Configuration")]
 #[description = "Admin/Moderator commands that configure the bot"]
-#[commands(prefix, command, resetprefix)]
+#[commands(prefix, command, alias, autoresponse, resetprefix, config)]
 pub struct Config;
 
 #[group("Support")]
 #[description = "Support commands for the bot"]
-#[commands(help, support, info)]
+#[commands(help, support, info, stats, errors, blocklist)]
 pub struct Support;
 
 #[group("Starboard")]
@@ -68,7 +69,7 @@ pub struct Support;
 pub struct Starboard;
 
 #[group("Voice")]
-#[description = "Commands used for voice chat"]
+#[description = "Commands used for voice chat. You have to be in the voice chat to use them"]
 #[commands(summon, disconnect)]
 pub struct Voice;
 
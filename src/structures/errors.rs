@@ -1,4 +1,4 @@
-use std::fmt;
+use crate::structures::{Locale, PermissionLevel};
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -8,14 +8,20 @@ pub enum JesterError<'a> {
     UnsuccessfulError(&'a str),
 }
 
-impl fmt::Display for JesterError<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+/*
+ * Errors are rendered with the guild's message catalog
+ * The inner strings are also looked up in the catalog, so untranslated ones are shown as is
+ */
+impl JesterError<'_> {
+    pub fn localize(&self, locale: &Locale) -> String {
         match *self {
-            JesterError::PermissionError(perm) =>
-                write!(f, "{}", perm),
-            JesterError::MissingError(missing) => write!(f, "Please provide a {}!", missing),
-            JesterError::UnsuccessfulError(cmd) =>
-                write!(f, "{} unsuccessful. The user must be in the guild and the bot must be above the user's role!", cmd)
+            JesterError::PermissionError(perm) => perm.localize(locale),
+            JesterError::MissingError(missing) => {
+                locale.format("error.missing", &[("item", &locale.get(missing))])
+            }
+            JesterError::UnsuccessfulError(cmd) => {
+                locale.format("error.unsuccessful", &[("command", &locale.get(cmd))])
+            }
         }
     }
 }
@@ -26,26 +32,30 @@ pub enum PermissionType<'b> {
     UserPerm(&'b str),
     Mention(&'b str, &'b str),
     External(&'b str),
+    Level(PermissionLevel),
 }
 
-impl fmt::Display for PermissionType<'_> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl PermissionType<'_> {
+    pub fn localize(&self, locale: &Locale) -> String {
         match *self {
-            PermissionType::UserPerm(perm) => writeln!(
-                f,
-                "You can't execute this command because you do not have the `{}` permission!",
-                perm
+            PermissionType::UserPerm(perm) => locale.format(
+                "error.user_permission",
+                &[("permission", &locale.get(perm))],
             ),
-            PermissionType::Mention(cmd, perm) => write!(
-                f,
-                "I can't {} an {}! Please demote the user and try again",
-                cmd, perm
+            PermissionType::Mention(cmd, perm) => locale.format(
+                "error.mention_permission",
+                &[("command", &locale.get(cmd)), ("role", &locale.get(perm))],
+            ),
+            PermissionType::External(perm) => {
+                locale.format("error.bot_permission", &[("permission", &locale.get(perm))])
+            }
+            PermissionType::Level(level) => locale.format(
+                "error.level_permission",
+                &[
+                    ("level", &locale.get(&format!("level.{}", level.as_str()))),
+                    ("permission", &locale.get(level.permission_name())),
+                ],
             ),
-            PermissionType::External(perm) => write!(
-                f,
-                "The bot can't execute this because it doesn't have the `{}` permission! Please grant it on the bot's role.",
-                perm
-            )
         }
     }
 }
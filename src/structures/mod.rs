@@ -2,9 +2,23 @@ pub mod cmd_data;
 pub mod commands;
 pub mod errors;
 
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
+use regex::{Regex, RegexBuilder};
 use serde::{Deserialize, Serialize};
+use serenity::{
+    builder::CreateEmbed,
+    model::{
+        channel::ReactionType,
+        id::{ChannelId, GuildId, RoleId, UserId},
+        Permissions,
+    },
+};
 
 #[derive(Debug, Deserialize)]
 pub struct CommitResponse {
@@ -57,3 +71,577 @@ pub struct GifResult {
 pub struct TenorMedia {
     pub url: String,
 }
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RuleScope {
+    Guild,
+    Channel(ChannelId),
+    Role(RoleId),
+}
+
+impl RuleScope {
+    pub fn from_db(scope_type: &str, scope_id: i64) -> Self {
+        match scope_type {
+            "channel" => RuleScope::Channel(ChannelId::from(scope_id as u64)),
+            "role" => RuleScope::Role(RoleId::from(scope_id as u64)),
+            _ => RuleScope::Guild,
+        }
+    }
+
+    // Guild-wide rules use the guild ID as the scope ID to keep the primary key non-null
+    pub fn to_db(self, guild_id: GuildId) -> (&'static str, i64) {
+        match self {
+            RuleScope::Guild => ("guild", guild_id.0 as i64),
+            RuleScope::Channel(channel_id) => ("channel", channel_id.0 as i64),
+            RuleScope::Role(role_id) => ("role", role_id.0 as i64),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommandRule {
+    pub name: String,
+    pub is_group: bool,
+    pub scope: RuleScope,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct CommandLocation {
+    pub command: String,
+    pub group: String,
+    pub bucket: Option<&'static str>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CooldownScope {
+    User,
+    Channel,
+    Guild,
+}
+
+impl CooldownScope {
+    pub fn from_db(scope_type: &str) -> Self {
+        match scope_type {
+            "channel" => CooldownScope::Channel,
+            "guild" => CooldownScope::Guild,
+            _ => CooldownScope::User,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CooldownScope::User => "user",
+            CooldownScope::Channel => "channel",
+            CooldownScope::Guild => "guild",
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct CooldownOverride {
+    pub scope: CooldownScope,
+    pub uses: u32,
+    pub seconds: u64,
+}
+
+// Recent uses of a command by one cooldown scope, along with the window they count in
+#[derive(Clone, Debug)]
+pub struct TrackedUses {
+    pub uses: Vec<Instant>,
+    pub window: Duration,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum BlockTarget {
+    User(UserId),
+    Guild(GuildId),
+}
+
+impl BlockTarget {
+    pub fn from_db(target_type: &str, target_id: i64) -> Option<Self> {
+        match target_type {
+            "user" => Some(BlockTarget::User(UserId::from(target_id as u64))),
+            "guild" => Some(BlockTarget::Guild(GuildId::from(target_id as u64))),
+            _ => None,
+        }
+    }
+
+    pub fn to_db(self) -> (&'static str, i64) {
+        match self {
+            BlockTarget::User(user_id) => ("user", user_id.0 as i64),
+            BlockTarget::Guild(guild_id) => ("guild", guild_id.0 as i64),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TriggerType {
+    Exact,
+    Contains,
+    Regex,
+}
+
+impl TriggerType {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "exact" => Some(TriggerType::Exact),
+            "contains" => Some(TriggerType::Contains),
+            "regex" => Some(TriggerType::Regex),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TriggerType::Exact => "exact",
+            TriggerType::Contains => "contains",
+            TriggerType::Regex => "regex",
+        }
+    }
+
+    /*
+     * Every trigger is matched with a regex, so all of them are compiled once when they're loaded
+     * Phrases ignore case, and don't match inside of other words, ex. `69` doesn't match `1690`
+     */
+    pub fn compile(self, trigger: &str) -> Result<Regex, regex::Error> {
+        let pattern = match self {
+            TriggerType::Exact => format!(r"(?i)^\s*{}\s*$", regex::escape(trigger)),
+            TriggerType::Contains => {
+                let start = if trigger.starts_with(char::is_alphanumeric) {
+                    r"\b"
+                } else {
+                    ""
+                };
+                let end = if trigger.ends_with(char::is_alphanumeric) {
+                    r"\b"
+                } else {
+                    ""
+                };
+
+                format!("(?i){}{}{}", start, regex::escape(trigger), end)
+            }
+            TriggerType::Regex => trigger.to_owned(),
+        };
+
+        // Keeps a guild's regexes from using up too much memory
+        RegexBuilder::new(&pattern).size_limit(1 << 16).build()
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AutoResponder {
+    pub id: i32,
+    pub trigger_type: TriggerType,
+    pub trigger: String,
+    pub matcher: Regex,
+    pub response: Option<String>,
+    pub reaction: Option<String>,
+    pub cooldown: Option<u64>,
+    pub channels: Vec<ChannelId>,
+}
+
+impl AutoResponder {
+    pub fn is_match(&self, channel_id: ChannelId, content: &str) -> bool {
+        (self.channels.is_empty() || self.channels.contains(&channel_id))
+            && self.matcher.is_match(content)
+    }
+}
+
+// A named board that quotes messages once enough members react with its emoji
+#[derive(Clone, Debug)]
+pub struct Starboard {
+    pub name: String,
+    pub emoji: ReactionType,
+    pub threshold: u32,
+    pub channel_id: ChannelId,
+}
+
+impl Starboard {
+    // Custom emojis are compared by ID since their names can change
+    pub fn uses_emoji(&self, emoji: &ReactionType) -> bool {
+        match (&self.emoji, emoji) {
+            (ReactionType::Custom { id: board_id, .. }, ReactionType::Custom { id, .. }) => {
+                board_id == id
+            }
+            (ReactionType::Unicode(board_name), ReactionType::Unicode(name)) => board_name == name,
+            _ => false,
+        }
+    }
+}
+
+// Which reactions count towards a guild's boards. Guilds without filters use the reaction count
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct StarboardFilters {
+    pub ignore_self: bool,
+    pub ignore_bots: bool,
+    pub ignore_bot_stars: bool,
+}
+
+impl StarboardFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+// Users that reacted to a message with a board's emoji, and whether they're bots
+#[derive(Clone, Debug)]
+pub struct StarReactors {
+    pub users: HashMap<UserId, bool>,
+    pub fetched: Instant,
+}
+
+// Ordered from lowest to highest, so listing a guild's roles shows admins last
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum PermissionLevel {
+    Dj,
+    Moderator,
+    Admin,
+}
+
+impl PermissionLevel {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "dj" => Some(PermissionLevel::Dj),
+            "mod" | "moderator" => Some(PermissionLevel::Moderator),
+            "admin" | "administrator" => Some(PermissionLevel::Admin),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PermissionLevel::Dj => "dj",
+            PermissionLevel::Moderator => "moderator",
+            PermissionLevel::Admin => "admin",
+        }
+    }
+
+    // Roles for a higher level also grant the lower ones, ex. an admin role can clear the music queue
+    pub fn granted_by(self) -> &'static [PermissionLevel] {
+        match self {
+            PermissionLevel::Dj => &[
+                PermissionLevel::Dj,
+                PermissionLevel::Moderator,
+                PermissionLevel::Admin,
+            ],
+            PermissionLevel::Moderator => &[PermissionLevel::Moderator, PermissionLevel::Admin],
+            PermissionLevel::Admin => &[PermissionLevel::Admin],
+        }
+    }
+
+    // The Discord permission that grants the level when a member doesn't have one of its roles
+    pub fn default_permission(self) -> Permissions {
+        match self {
+            PermissionLevel::Dj | PermissionLevel::Moderator => Permissions::MANAGE_MESSAGES,
+            PermissionLevel::Admin => Permissions::ADMINISTRATOR,
+        }
+    }
+
+    // Message catalog fragment for the default permission
+    pub fn permission_name(self) -> &'static str {
+        match self {
+            PermissionLevel::Dj | PermissionLevel::Moderator => "manage messages",
+            PermissionLevel::Admin => "administrator",
+        }
+    }
+}
+
+// Output of command bodies that are shared between text and slash commands
+pub enum CommandReply {
+    Text(String),
+    Embed(CreateEmbed),
+    Reaction(ReactionType),
+}
+
+// A rich custom command reply. Commands with several of these send a random one
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CustomResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub embed: Option<CustomEmbed>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attachments: Vec<String>,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct CustomEmbed {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_color",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub color: Option<u32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub fields: Vec<CustomEmbedField>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct CustomEmbedField {
+    pub name: String,
+    pub value: String,
+    #[serde(default)]
+    pub inline: bool,
+}
+
+// A guild's custom command as it's cached for the unrecognised command hook
+#[derive(Clone, Debug, Default)]
+pub struct CustomCommand {
+    pub content: Option<String>,
+    pub responses: Option<Vec<CustomResponse>>,
+    pub roles: Vec<RoleId>,
+    pub channels: Vec<ChannelId>,
+    pub cooldown: Option<u64>,
+    pub uses: i64,
+}
+
+impl CustomCommand {
+    pub fn from_db(
+        content: Option<String>,
+        responses: Option<String>,
+        roles: Vec<i64>,
+        channels: Vec<i64>,
+        cooldown: Option<i32>,
+        uses: i64,
+    ) -> Self {
+        Self {
+            content,
+            responses: responses.and_then(|responses| serde_json::from_str(&responses).ok()),
+            roles: roles
+                .into_iter()
+                .map(|id| RoleId::from(id as u64))
+                .collect(),
+            channels: channels
+                .into_iter()
+                .map(|id| ChannelId::from(id as u64))
+                .collect(),
+            cooldown: cooldown.map(|seconds| seconds as u64),
+            uses,
+        }
+    }
+}
+
+// A custom command as it's written to and read from `command export` files
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct ExportedCommand {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub responses: Option<Vec<CustomResponse>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub category: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub roles: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub channels: Vec<u64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cooldown: Option<u64>,
+}
+
+// Colors can be given as a number or a hex string, ex. `16738740` or `"#ff69b4"`
+fn deserialize_color<'de, D>(deserializer: D) -> Result<Option<u32>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Color {
+        Number(u32),
+        Hex(String),
+    }
+
+    match Color::deserialize(deserializer)? {
+        Color::Number(color) => Ok(Some(color)),
+        Color::Hex(hex) => u32::from_str_radix(hex.trim_start_matches('#'), 16)
+            .map(Some)
+            .map_err(serde::de::Error::custom),
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct CommandUsage {
+    pub guild_id: Option<GuildId>,
+    pub channel_id: ChannelId,
+    pub user_id: UserId,
+    pub command: String,
+    pub duration_ms: u32,
+    pub success: bool,
+    pub used_at: u64,
+}
+
+// Every loaded message catalog, keyed by language code
+#[derive(Debug, Default)]
+pub struct LocaleCatalog {
+    pub languages: HashMap<String, HashMap<String, String>>,
+}
+
+#[derive(Clone, Debug)]
+pub struct Locale {
+    pub catalog: Arc<LocaleCatalog>,
+    pub language: String,
+}
+
+impl Locale {
+    /*
+     * Looks up a message in the guild's language, then in English
+     * If neither catalog has the key, the key itself is returned
+     */
+    pub fn get(&self, key: &str) -> String {
+        [self.language.as_str(), "en"]
+            .iter()
+            .find_map(|language| self.catalog.languages.get(*language)?.get(key))
+            .cloned()
+            .unwrap_or_else(|| key.to_owned())
+    }
+
+    /*
+     * Only looks in the guild's language and returns the default otherwise
+     * Used for text where the English version lives in the code, like command descriptions
+     */
+    pub fn get_or(&self, key: &str, default: &str) -> String {
+        self.catalog
+            .languages
+            .get(&self.language)
+            .and_then(|messages| messages.get(key))
+            .cloned()
+            .unwrap_or_else(|| default.to_owned())
+    }
+
+    /*
+     * Same as get, but replaces every {name} placeholder with its value
+     * Values are never scanned again, so a value containing `{name}` is inserted as is
+     */
+    pub fn format(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let template = self.get(key);
+        let mut message = String::with_capacity(template.len());
+        let mut rest = template.as_str();
+
+        while let Some(start) = rest.find('{') {
+            message.push_str(&rest[..start]);
+            let after_brace = &rest[start + 1..];
+
+            let placeholder = after_brace.find('}').and_then(|end| {
+                let name = &after_brace[..end];
+
+                args.iter()
+                    .find(|(arg_name, _)| *arg_name == name)
+                    .map(|(_, value)| (end, value))
+            });
+
+            match placeholder {
+                Some((end, value)) => {
+                    message.push_str(&value.to_string());
+                    rest = &after_brace[end + 1..];
+                }
+                // Unknown placeholders and stray braces are kept
+                None => {
+                    message.push('{');
+                    rest = after_brace;
+                }
+            }
+        }
+
+        message.push_str(rest);
+
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_locale(language: &str) -> Locale {
+        let messages = |entries: &[(&str, &str)]| {
+            entries
+                .iter()
+                .map(|(key, value)| (key.to_string(), value.to_string()))
+                .collect::<HashMap<String, String>>()
+        };
+
+        let mut catalog = LocaleCatalog::default();
+        catalog.languages.insert(
+            "en".to_owned(),
+            messages(&[
+                ("greeting", "Hello {name}!"),
+                ("only_en", "English only"),
+                ("pair", "{first} and {second}"),
+            ]),
+        );
+        catalog
+            .languages
+            .insert("es".to_owned(), messages(&[("greeting", "¡Hola {name}!")]));
+
+        Locale {
+            catalog: Arc::new(catalog),
+            language: language.to_owned(),
+        }
+    }
+
+    #[test]
+    fn get_falls_back_to_english_then_the_key() {
+        let locale = get_locale("es");
+
+        assert_eq!(locale.get("greeting"), "¡Hola {name}!");
+        assert_eq!(locale.get("only_en"), "English only");
+        assert_eq!(locale.get("missing.key"), "missing.key");
+        assert_eq!(get_locale("fr").get("greeting"), "Hello {name}!");
+    }
+
+    #[test]
+    fn format_replaces_placeholders() {
+        let locale = get_locale("en");
+
+        assert_eq!(
+            locale.format("greeting", &[("name", &"Jester")]),
+            "Hello Jester!"
+        );
+        assert_eq!(
+            locale.format("pair", &[("second", &2), ("first", &1)]),
+            "1 and 2"
+        );
+    }
+
+    #[test]
+    fn format_uses_the_fallback_message() {
+        let locale = get_locale("es");
+
+        assert_eq!(locale.format("only_en", &[("name", &"x")]), "English only");
+        assert_eq!(
+            locale.format("missing.key", &[("name", &"x")]),
+            "missing.key"
+        );
+    }
+
+    #[test]
+    fn format_keeps_unknown_placeholders() {
+        let locale = get_locale("en");
+
+        assert_eq!(locale.format("greeting", &[]), "Hello {name}!");
+        assert_eq!(locale.format("pair", &[("first", &1)]), "1 and {second}");
+    }
+
+    #[test]
+    fn format_does_not_substitute_inside_values() {
+        let locale = get_locale("en");
+
+        assert_eq!(
+            locale.format("pair", &[("first", &"{second}"), ("second", &"b")]),
+            "{second} and b"
+        );
+    }
+}